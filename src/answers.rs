@@ -0,0 +1,242 @@
+//! The accepted answers for each day, kept in `answers.toml` and checked by `aoc verify`
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::registry::Day;
+use crate::solution::Answer;
+
+/// An accepted answer as written in the file, a number or a string
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Accepted {
+    Integer(i64),
+    Text(String),
+}
+
+impl From<Accepted> for Answer {
+    fn from(accepted: Accepted) -> Self {
+        match accepted {
+            Accepted::Integer(n) => Answer::Integer(n as i128),
+            Accepted::Text(s) if s.contains('\n') => Answer::lines(&s),
+            Accepted::Text(s) => Answer::Text(s),
+        }
+    }
+}
+
+/// One day's table in the file
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayTable {
+    part1: Option<Accepted>,
+    part2: Option<Accepted>,
+}
+
+/// The accepted answers, by day and part
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<u8, [Option<Answer>; 2]>,
+}
+
+impl Answers {
+    /// Parse the answers from TOML, a `[day-N]` table per day with `part1` and `part2` keys
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        let tables: BTreeMap<String, DayTable> = toml::from_str(text)?;
+
+        let mut days = BTreeMap::new();
+        for (name, table) in tables {
+            let day = name
+                .strip_prefix("day-")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("expected a table named day-N, not [{name}]"))?;
+
+            days.insert(day, [table.part1, table.part2].map(|p| p.map(Answer::from)));
+        }
+
+        Ok(Self { days })
+    }
+
+    /// Read the answers from a file, see [`Answers::parse`]
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read answers {}: {e}", path.display()))?;
+        Self::parse(&text).map_err(|e| format!("{}: {e}", path.display()).into())
+    }
+
+    /// The accepted answer to the part, 1 or 2, if there is one
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        let parts = self.days.get(&day)?;
+        parts.get(usize::from(part).checked_sub(1)?)?.as_ref()
+    }
+
+    /// The days that have answers, in order
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.days.keys().copied()
+    }
+}
+
+/// How a part's answer compared with the accepted one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    /// The answer was wrong, with a diff of the accepted answer (`-`) against it (`+`)
+    Fail(String),
+    /// The input couldn't be parsed or the part couldn't be solved
+    Error(String),
+}
+
+/// The verdict on one part of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub verdict: Verdict,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.verdict == Verdict::Pass
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {} part {}: ", self.day, self.part)?;
+
+        match &self.verdict {
+            Verdict::Pass => write!(f, "ok"),
+            Verdict::Fail(diff) => write!(f, "FAILED\n{diff}"),
+            Verdict::Error(e) => write!(f, "ERROR {e}"),
+        }
+    }
+}
+
+/// Solve every part of the day that has an accepted answer, and compare them
+pub fn check_day(day: &Day, input: &str, answers: &Answers) -> Vec<Check> {
+    let parts = (1..=2).filter_map(|part| Some((part, answers.get(day.day, part)?)));
+    let check = |part, verdict| Check {
+        day: day.day,
+        part,
+        verdict,
+    };
+
+    let solution = match (day.parse)(input) {
+        Ok(solution) => solution,
+        Err(e) => {
+            return parts
+                .map(|(part, _)| check(part, Verdict::Error(e.to_string())))
+                .collect()
+        }
+    };
+
+    parts
+        .map(|(part, accepted)| {
+            let verdict = match solution.solve(part) {
+                Ok(answer) => match diff(accepted, &answer) {
+                    Some(diff) => Verdict::Fail(diff),
+                    None => Verdict::Pass,
+                },
+                Err(e) => Verdict::Error(e.to_string()),
+            };
+            check(part, verdict)
+        })
+        .collect()
+}
+
+/// A line by line diff of the answers, or `None` if they're the same
+///
+/// The answers are compared as they're printed, so the number `42` matches the text `"42"`. Lines
+/// that differ are shown as `- accepted` and `+ actual`, and the lines they share are indented.
+pub fn diff(accepted: &Answer, actual: &Answer) -> Option<String> {
+    let (accepted, actual) = (accepted.to_string(), actual.to_string());
+    if accepted == actual {
+        return None;
+    }
+
+    let accepted: Vec<_> = accepted.lines().collect();
+    let actual: Vec<_> = actual.lines().collect();
+
+    let mut diff = Vec::new();
+    for i in 0..accepted.len().max(actual.len()) {
+        match (accepted.get(i), actual.get(i)) {
+            (Some(a), Some(b)) if a == b => diff.push(format!("  {a}")),
+            (a, b) => {
+                diff.extend(a.map(|a| format!("- {a}")));
+                diff.extend(b.map(|b| format!("+ {b}")));
+            }
+        }
+    }
+
+    Some(diff.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    const ANSWERS: &str = "
+[day-6]
+part1 = 7
+part2 = \"20\"
+
+[day-10]
+part1 = 13140
+part2 = '''
+#.
+.#
+'''
+";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.days().collect::<Vec<_>>(), [6, 10]);
+        assert_eq!(answers.get(6, 1), Some(&Answer::Integer(7)));
+        assert_eq!(answers.get(6, 2), Some(&Answer::Text("20".to_string())));
+        assert_eq!(answers.get(10, 1), Some(&Answer::Integer(13140)));
+        assert_eq!(answers.get(10, 2), Some(&Answer::lines("#.\n.#")));
+        assert_eq!(answers.get(10, 3), None);
+        assert_eq!(answers.get(1, 1), None);
+
+        assert!(Answers::parse("[day-x]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day-1]\npart3 = 1").is_err());
+    }
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            diff(&Answer::Integer(42), &Answer::from("42".to_string())),
+            None
+        );
+        assert_eq!(
+            diff(&Answer::Integer(42), &Answer::Integer(24)).unwrap(),
+            "- 42\n+ 24"
+        );
+        assert_eq!(
+            diff(&Answer::lines("#.\n.#\n##"), &Answer::lines("#.\n..")).unwrap(),
+            "  #.\n- .#\n+ ..\n- ##"
+        );
+    }
+
+    #[test]
+    fn test_check_day() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let day = registry::find(6).unwrap();
+
+        let checks = check_day(day, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n", &answers);
+        assert_eq!(checks.len(), 2);
+        assert!(checks[0].passed());
+        assert_eq!(checks[0].to_string(), "day 6 part 1: ok");
+        assert_eq!(checks[1].verdict, Verdict::Fail("- 20\n+ 19".to_string()));
+
+        let day = registry::find(10).unwrap();
+        let checks = check_day(day, "jump 3\n", &answers);
+        assert!(matches!(checks[0].verdict, Verdict::Error(_)));
+    }
+}
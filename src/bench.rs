@@ -0,0 +1,237 @@
+//! Timing each day's parse and parts, and comparing them against a saved baseline
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::registry::Day;
+
+/// The fastest, median and slowest of a set of runs
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Timings {
+    #[serde(with = "seconds")]
+    pub min: Duration,
+    #[serde(with = "seconds")]
+    pub median: Duration,
+    #[serde(with = "seconds")]
+    pub max: Duration,
+}
+
+impl Timings {
+    /// Summarize the samples, which must not be empty
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        samples.sort_unstable();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// The timings of one day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Timings,
+    pub part1: Timings,
+    pub part2: Timings,
+}
+
+impl DayBench {
+    /// Each stage that was timed, with its name
+    pub fn stages(&self) -> [(&'static str, &Timings); 3] {
+        [
+            ("parse", &self.parse),
+            ("part 1", &self.part1),
+            ("part 2", &self.part2),
+        ]
+    }
+}
+
+/// Parse the input and solve both parts `iterations` times, which must be at least 1, timing
+/// each stage
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> Result<DayBench, Box<dyn Error>> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let solution = (day.parse)(input)?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        solution.part1()?;
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        solution.part2()?;
+        part2.push(start.elapsed());
+    }
+
+    Ok(DayBench {
+        day: day.day,
+        parse: Timings::new(parse),
+        part1: Timings::new(part1),
+        part2: Timings::new(part2),
+    })
+}
+
+/// A stage whose median got slower than the baseline allows
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slowdown {
+    pub day: u8,
+    pub stage: &'static str,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Slowdown {
+    /// How many times slower the stage is than the baseline
+    pub fn ratio(&self) -> f64 {
+        self.current.as_secs_f64() / self.baseline.as_secs_f64()
+    }
+}
+
+impl Display for Slowdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} {}: median {:.1?} was {:.1?}, {:.2}x slower",
+            self.day,
+            self.stage,
+            self.current,
+            self.baseline,
+            self.ratio()
+        )
+    }
+}
+
+/// Every stage whose median is more than `threshold` slower than the same day's stage in the baseline
+///
+/// The threshold is a fraction, so `0.1` allows the median to be up to 10% slower. Days missing
+/// from the baseline are not compared.
+pub fn find_slowdowns(
+    current: &[DayBench],
+    baseline: &[DayBench],
+    threshold: f64,
+) -> Vec<Slowdown> {
+    let mut slowdowns = Vec::new();
+
+    for bench in current {
+        let Some(base) = baseline.iter().find(|base| base.day == bench.day) else {
+            continue;
+        };
+
+        let stages = bench.stages().into_iter().zip(base.stages());
+        for ((stage, timings), (_, base_timings)) in stages {
+            let allowed = base_timings.median.as_secs_f64() * (1.0 + threshold);
+            if timings.median.as_secs_f64() > allowed {
+                slowdowns.push(Slowdown {
+                    day: bench.day,
+                    stage,
+                    baseline: base_timings.median,
+                    current: timings.median,
+                });
+            }
+        }
+    }
+
+    slowdowns
+}
+
+/// Read a baseline saved by [`save_baseline`]
+pub fn load_baseline(path: &Path) -> Result<Vec<DayBench>, Box<dyn Error>> {
+    let baseline = fs::read_to_string(path)
+        .map_err(|e| format!("failed to read baseline {}: {e}", path.display()))?;
+    Ok(serde_json::from_str(&baseline)?)
+}
+
+/// Save the timings as a baseline to compare later runs against
+pub fn save_baseline(path: &Path, benches: &[DayBench]) -> Result<(), Box<dyn Error>> {
+    fs::write(path, serde_json::to_string_pretty(benches)?)?;
+    Ok(())
+}
+
+/// Durations as fractional seconds, like the JSON output of `aoc run`
+mod seconds {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let seconds = f64::deserialize(deserializer)?;
+        Duration::try_from_secs_f64(seconds).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(millis: &[u64]) -> Vec<Duration> {
+        millis.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    fn bench(day: u8, parse: u64, part1: u64, part2: u64) -> DayBench {
+        DayBench {
+            day,
+            parse: Timings::new(millis(&[parse])),
+            part1: Timings::new(millis(&[part1])),
+            part2: Timings::new(millis(&[part2])),
+        }
+    }
+
+    #[test]
+    fn test_timings() {
+        let timings = Timings::new(millis(&[5, 1, 3]));
+        assert_eq!(timings.min, Duration::from_millis(1));
+        assert_eq!(timings.median, Duration::from_millis(3));
+        assert_eq!(timings.max, Duration::from_millis(5));
+
+        let timings = Timings::new(millis(&[4, 1, 2, 8]));
+        assert_eq!(timings.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_find_slowdowns() {
+        let baseline = vec![bench(1, 10, 10, 10), bench(2, 10, 10, 10)];
+        let current = vec![bench(1, 10, 11, 20), bench(3, 100, 100, 100)];
+
+        let slowdowns = find_slowdowns(&current, &baseline, 0.2);
+        assert_eq!(slowdowns.len(), 1);
+        assert_eq!(slowdowns[0].day, 1);
+        assert_eq!(slowdowns[0].stage, "part 2");
+        assert_eq!(slowdowns[0].ratio(), 2.0);
+
+        assert_eq!(find_slowdowns(&current, &baseline, 0.05).len(), 2);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let benches = vec![bench(1, 10, 20, 30)];
+        let json = serde_json::to_string(&benches).unwrap();
+        assert!(json.contains(r#""median":0.01"#));
+
+        let loaded: Vec<DayBench> = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, benches);
+    }
+}
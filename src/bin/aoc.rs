@@ -0,0 +1,581 @@
+use std::error::Error;
+use std::io;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+use serde::Serialize;
+
+use aoc_2022::answers::{self, Answers, Check, Verdict};
+use aoc_2022::bench::{self, DayBench};
+use aoc_2022::cli::{
+    Bench, Cli, Command, CraneModel, Day1, Day2, Day3, Day4, Day5, Day6, Day7, Day8, Day9, Game,
+    Output, Run, Verify,
+};
+use aoc_2022::days::day_1;
+use aoc_2022::days::day_2::{Play, Ruleset, StrategyGuide};
+use aoc_2022::days::day_3::Rucksacks;
+use aoc_2022::days::day_4::Assignments;
+use aoc_2022::days::day_5::{self, Cargo, Crane, CrateMover9000, CrateMover9001, Limited};
+use aoc_2022::days::day_6::Markers;
+use aoc_2022::days::day_7::{self, FileSystem, Tree};
+use aoc_2022::days::day_8::{self, Forest};
+use aoc_2022::days::day_9::{Motions, Rope};
+use aoc_2022::input;
+use aoc_2022::intervals::IntervalSet;
+use aoc_2022::registry::{self, Day, DAYS};
+use aoc_2022::solution::{Answer, Solution};
+
+fn main() -> ExitCode {
+    let args = Cli::parse();
+
+    let result = match args.command {
+        Command::Run(run) => solve(run),
+        Command::Bench(bench) => benchmark(bench),
+        Command::Verify(verify) => verify_answers(verify),
+        Command::Day1(day1) => rank_elves(day1),
+        Command::Day2(day2) => audit_guide(day2),
+        Command::Day3(day3) => score_rucksacks(day3),
+        Command::Day4(day4) => survey_sections(day4),
+        Command::Day5(day5) => rearrange_crates(day5),
+        Command::Day6(day6) => find_markers(day6),
+        Command::Day7(day7) => explore_filesystem(day7),
+        Command::Day8(day8) => draw_forest(day8),
+        Command::Day9(day9) => pull_rope(day9),
+    };
+
+    // print the error for people rather than its Debug form
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn solve(args: Run) -> Result<(), Box<dyn Error>> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    if args.all {
+        for day in DAYS {
+            solve_day(day, &day.default_input(), &parts, args.output)?;
+        }
+
+        return Ok(());
+    }
+
+    let day = find_day(args.day.ok_or("a day is required")?)?;
+    let input = args.input.unwrap_or_else(|| day.default_input());
+
+    solve_day(day, &input, &parts, args.output)
+}
+
+fn find_day(day: u8) -> Result<&'static Day, String> {
+    registry::find(day).ok_or_else(|| format!("day {day} is not solved yet"))
+}
+
+/// One part's answer, printed as a line of JSON
+#[derive(Serialize)]
+struct PartOutput<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a Answer,
+    /// Seconds taken to parse the input, shared by both parts
+    parse_time: f64,
+    /// Seconds taken to solve the part
+    solve_time: f64,
+}
+
+fn solve_day(day: &Day, path: &Path, parts: &[u8], output: Output) -> Result<(), Box<dyn Error>> {
+    let input = input::read_to_string(path)?;
+
+    let (solution, parse_time) = timed(|| (day.parse)(&input));
+    let solution = solution.map_err(|e| format!("{}: {e}", input::name(path)))?;
+
+    if output == Output::Text {
+        println!("Day {}: {}", day.day, day.title);
+    }
+
+    for &part in parts {
+        let (answer, solve_time) = timed(|| solution.solve(part));
+        let answer = answer?;
+
+        match output {
+            Output::Text => {
+                let answer = answer.to_string();
+
+                // multi-line answers, like the CRT image, start on their own line
+                if answer.contains('\n') {
+                    println!("  part {part}:\n{answer}");
+                } else {
+                    println!("  part {part}: {answer}");
+                }
+            }
+            Output::Json => {
+                let output = PartOutput {
+                    day: day.day,
+                    part,
+                    answer: &answer,
+                    parse_time: parse_time.as_secs_f64(),
+                    solve_time: solve_time.as_secs_f64(),
+                };
+
+                println!("{}", serde_json::to_string(&output)?);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+fn benchmark(args: Bench) -> Result<(), Box<dyn Error>> {
+    let days = match args.day {
+        Some(day) => vec![(find_day(day)?, args.input)],
+        None => DAYS.iter().map(|day| (day, None)).collect(),
+    };
+
+    // load it first, so a bad path is found before all the timing
+    let baseline = args
+        .baseline
+        .as_deref()
+        .map(bench::load_baseline)
+        .transpose()?;
+
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "day", "stage", "min", "median", "max"
+    );
+
+    let iterations = usize::try_from(args.iterations)?;
+    let mut benches = Vec::<DayBench>::new();
+    for (day, input) in days {
+        let path = input.unwrap_or_else(|| day.default_input());
+        let input = input::read_to_string(&path)?;
+        let bench = bench::bench_day(day, &input, iterations)
+            .map_err(|e| format!("{}: {e}", input::name(&path)))?;
+
+        for (stage, timings) in bench.stages() {
+            println!(
+                "{:>3}  {stage:<6}  {:>10}  {:>10}  {:>10}",
+                bench.day,
+                format!("{:.1?}", timings.min),
+                format!("{:.1?}", timings.median),
+                format!("{:.1?}", timings.max),
+            );
+        }
+
+        benches.push(bench);
+    }
+
+    if let Some(path) = &args.save {
+        bench::save_baseline(path, &benches)?;
+        println!("saved the baseline to {}", path.display());
+    }
+
+    if let Some(baseline) = baseline {
+        let slowdowns = bench::find_slowdowns(&benches, &baseline, args.threshold / 100.0);
+        for slowdown in &slowdowns {
+            println!("SLOWER {slowdown}");
+        }
+
+        if !slowdowns.is_empty() {
+            return Err(format!(
+                "{} stages are more than {}% slower than the baseline",
+                slowdowns.len(),
+                args.threshold
+            )
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+fn verify_answers(args: Verify) -> Result<(), Box<dyn Error>> {
+    let answers = Answers::load(&args.answers)?;
+    let days: Vec<u8> = match args.day {
+        Some(day) => vec![day],
+        None => answers.days().collect(),
+    };
+
+    let mut checks = Vec::<Check>::new();
+    for day in days {
+        let solved = find_day(day)?;
+        let path = solved.default_input();
+        let day_checks = match input::read_to_string(&path) {
+            Ok(input) => answers::check_day(solved, &input, &answers),
+            Err(e) => (1..=2)
+                .filter(|&part| answers.get(day, part).is_some())
+                .map(|part| Check {
+                    day,
+                    part,
+                    verdict: Verdict::Error(e.clone()),
+                })
+                .collect(),
+        };
+
+        if day_checks.is_empty() {
+            println!(
+                "day {day}: no accepted answers in {}",
+                args.answers.display()
+            );
+        }
+
+        for check in day_checks {
+            println!("{check}");
+            checks.push(check);
+        }
+    }
+
+    let failed = checks.iter().filter(|check| !check.passed()).count();
+    println!("{} passed, {failed} failed", checks.len() - failed);
+
+    if failed > 0 {
+        return Err(format!("{failed} answers don't match {}", args.answers.display()).into());
+    }
+
+    Ok(())
+}
+
+/// The path given for a day's tool, or the day's default input
+fn day_input(day: u8, input: Option<PathBuf>) -> Result<PathBuf, String> {
+    match input {
+        Some(path) => Ok(path),
+        None => Ok(find_day(day)?.default_input()),
+    }
+}
+
+fn rank_elves(args: Day1) -> Result<(), Box<dyn Error>> {
+    let path = day_input(1, args.input)?;
+    let input = input::read_to_string(&path)?;
+    let elves =
+        day_1::parse_elves(input.as_bytes()).map_err(|e| format!("{}: {e}", input::name(&path)))?;
+
+    let Some(summary) = day_1::summarize(&elves) else {
+        println!("there are no elves in {}", input::name(&path));
+        return Ok(());
+    };
+
+    let top = day_1::top_elves(elves.iter().copied(), args.top);
+    println!("Top {} of {} elves:", top.len(), summary.elves);
+    for (rank, elf) in top.iter().enumerate() {
+        println!(
+            "{:>4}. elf {:<5} {:>8} calories in {} items",
+            rank + 1,
+            elf.number,
+            elf.calories,
+            elf.items
+        );
+    }
+
+    println!(
+        "Together they carry {} calories",
+        top.iter().map(|elf| elf.calories).sum::<usize>()
+    );
+    println!(
+        "All elves: total {} calories, mean {:.1}, median {:.1}",
+        summary.total, summary.mean, summary.median
+    );
+
+    Ok(())
+}
+
+fn audit_guide(args: Day2) -> Result<(), Box<dyn Error>> {
+    let path = day_input(2, args.input)?;
+    let input = input::read_to_string(&path)?;
+    let rules = match args.game {
+        Game::Rps => Ruleset::default(),
+        Game::Rpssl => Ruleset::rock_paper_scissors_spock_lizard(),
+    };
+    let guide = StrategyGuide::parse_with(&input, rules)
+        .map_err(|e| format!("{}: {e}", input::name(&path)))?;
+
+    // a shape, its outcome, the round's score and the running total, or dashes for none
+    let play = |play: Option<(Play, usize)>| match play {
+        Some((play, total)) => format!(
+            "{:<8} {:<4} {:>5} {:>6}",
+            guide.rules.name(play.shape),
+            play.outcome,
+            play.score,
+            total
+        ),
+        None => format!("{:<8} {:<4} {:>5} {:>6}", "-", "-", "-", "-"),
+    };
+
+    println!(
+        "                | second column as a shape   | second column as an end    | best shape"
+    );
+    println!("round  opponent | shape    end  score  total | shape    end  score  total | shape    end  score  total");
+
+    for (i, round) in guide.report().into_iter().enumerate() {
+        println!(
+            "{:>5}  {:<8} | {} | {} | {}",
+            i + 1,
+            guide.rules.name(round.opponent),
+            play(round.as_shape),
+            play(round.as_end),
+            play(Some(round.best))
+        );
+    }
+
+    let total = |score: Result<usize, String>| score.map_or_else(|e| e, |s| s.to_string());
+    println!("as a shape: {}", total(guide.score()));
+    println!("as an end:  {}", total(guide.score_for_end()));
+    println!("best:       {}", guide.best_score());
+
+    Ok(())
+}
+
+fn score_rucksacks(args: Day3) -> Result<(), Box<dyn Error>> {
+    let path = day_input(3, args.input)?;
+    let input = input::read_to_string(&path)?;
+    let rucksacks = Rucksacks::parse(&input).map_err(|e| format!("{}: {e}", input::name(&path)))?;
+
+    let size = usize::from(args.group_size);
+    println!("misplaced items: {}", rucksacks.score());
+    println!(
+        "badges of {} groups of {size}: {}",
+        rucksacks.rucksacks.len() / size,
+        rucksacks.score_badges(size)?
+    );
+
+    Ok(())
+}
+
+fn survey_sections(args: Day4) -> Result<(), Box<dyn Error>> {
+    let path = day_input(4, args.input)?;
+    let input = input::read_to_string(&path)?;
+    let assignments =
+        Assignments::parse(&input).map_err(|e| format!("{}: {e}", input::name(&path)))?;
+
+    let k = args.more_than;
+    let sections = assignments.sections();
+    let shared = assignments.sections_cleaned_by_more_than(k);
+    let gaps = assignments.gaps();
+
+    let elves = if k == 1 { "elf" } else { "elves" };
+    println!(
+        "sections cleaned: {}{}",
+        sections.len(),
+        list_sections(&sections)
+    );
+    println!(
+        "cleaned by more than {k} {elves}: {}{}",
+        shared.len(),
+        list_sections(&shared)
+    );
+    println!("never cleaned: {}{}", gaps.len(), list_sections(&gaps));
+
+    Ok(())
+}
+
+/// The ranges of sections like ` (2-4, 6-8)`, or nothing if there are none
+fn list_sections(sections: &IntervalSet<usize>) -> String {
+    if sections.is_empty() {
+        return String::new();
+    }
+
+    let ranges: Vec<_> = sections
+        .iter()
+        .map(|range| format!("{}-{}", range.start(), range.end()))
+        .collect();
+    format!(" ({})", ranges.join(", "))
+}
+
+fn rearrange_crates(args: Day5) -> Result<(), Box<dyn Error>> {
+    let path = day_input(5, args.input)?;
+    let input = input::read_to_string(&path)?;
+    let cargo = Cargo::parse(&input).map_err(|e| format!("{}: {e}", input::name(&path)))?;
+
+    let mut crane: Box<dyn Crane> = match args.crane {
+        CraneModel::CrateMover9000 => Box::new(CrateMover9000),
+        CraneModel::CrateMover9001 => Box::new(CrateMover9001),
+    };
+    if let Some(capacity) = args.capacity {
+        let capacity = usize::try_from(capacity)?;
+        crane = Box::new(Limited { crane, capacity });
+    }
+
+    let mut stacks = cargo.stacks.clone();
+    if args.trace {
+        print!("{}", day_5::draw_stacks(&stacks));
+    }
+
+    for mov in &cargo.moves {
+        crane.make_move(&mut stacks, *mov);
+
+        if args.trace {
+            println!("\n{mov}\n");
+            print!("{}", day_5::draw_stacks(&stacks));
+        }
+    }
+
+    if args.trace {
+        println!();
+    }
+    println!(
+        "{} leaves on top: {}",
+        crane.name(),
+        day_5::top_of_stacks(&stacks)?
+    );
+
+    Ok(())
+}
+
+fn find_markers(args: Day6) -> Result<(), Box<dyn Error>> {
+    let path = day_input(6, args.input)?;
+    let reader = input::open(&path).map_err(|e| format!("{}: {e}", input::name(&path)))?;
+    let window = NonZeroUsize::try_from(usize::try_from(args.window)?)?;
+
+    let mut markers = Markers::new(reader, window);
+    if !args.all {
+        match markers.next().transpose()? {
+            Some(marker) => println!("first marker after character {marker}"),
+            None => println!("no marker of {window} distinct characters"),
+        }
+        return Ok(());
+    }
+
+    let mut count = 0;
+    for marker in markers {
+        println!("{}", marker?);
+        count += 1;
+    }
+    println!("{count} markers of {window} distinct characters");
+
+    Ok(())
+}
+
+fn explore_filesystem(args: Day7) -> Result<(), Box<dyn Error>> {
+    if let Some(dir) = args.from_dir {
+        let tree = Tree::from_dir(&dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+        tree.write_transcript(&mut io::stdout().lock())?;
+        return Ok(());
+    }
+
+    let path = day_input(7, args.input)?;
+    let input = input::read_to_string(&path)?;
+    let tree = FileSystem::parse(&input)
+        .map_err(|e| format!("{}: {e}", input::name(&path)))?
+        .tree;
+
+    if args.materialize {
+        let root = day_7::create_temp_dir()?;
+        tree.materialize(&root)?;
+        println!(
+            "created {} files and {} directories under {}",
+            tree.files().count(),
+            tree.directories().count() - 1,
+            root.display()
+        );
+        return Ok(());
+    }
+
+    if args.tree {
+        print!("{tree}");
+        println!();
+    }
+
+    println!("largest directories:");
+    for id in tree.largest_directories(args.largest) {
+        println!("{:>12}  {}", tree.size(id), tree.path(id));
+    }
+
+    println!();
+    println!("files by extension:");
+    for (extension, (count, size)) in tree.files_by_extension() {
+        let extension = extension.map_or_else(|| "(none)".to_string(), |e| format!(".{e}"));
+        println!("{size:>12}  {count:>5}  {extension}");
+    }
+
+    println!();
+    let used = tree.size(Tree::ROOT);
+    println!("{used} of {} used", args.disk);
+    if tree.update_fits(args.disk, args.update) {
+        println!("the update fits without deleting anything");
+        return Ok(());
+    }
+
+    match tree.smallest_to_free(args.disk, args.update) {
+        Some(id) => println!(
+            "delete {} to free {} for the update",
+            tree.path(id),
+            tree.size(id)
+        ),
+        None => println!("no directory frees enough for the update"),
+    }
+
+    Ok(())
+}
+
+fn draw_forest(args: Day8) -> Result<(), Box<dyn Error>> {
+    let path = day_input(8, args.input)?;
+    let input = input::read_to_string(&path)?;
+    let grid = Forest::parse(&input)
+        .map_err(|e| format!("{}: {e}", input::name(&path)))?
+        .grid;
+
+    let Some(file) = args.image else {
+        if args.heatmap {
+            print!("{}", day_8::render_heatmap(&grid, args.color));
+        } else {
+            print!("{}", day_8::render_forest(&grid, args.color));
+        }
+        return Ok(());
+    };
+
+    let scale = usize::try_from(args.scale)?;
+    let image = if args.heatmap {
+        day_8::heatmap_image(&grid, scale)
+    } else {
+        day_8::forest_image(&grid, scale)
+    };
+    image.save(&file)?;
+    println!(
+        "saved a {}x{} image to {}",
+        image.width(),
+        image.height(),
+        file.display()
+    );
+
+    Ok(())
+}
+
+fn pull_rope(args: Day9) -> Result<(), Box<dyn Error>> {
+    let path = day_input(9, args.input)?;
+    let input = input::read_to_string(&path)?;
+    let motions = Motions::parse(&input)
+        .map_err(|e| format!("{}: {e}", input::name(&path)))?
+        .motions;
+    let rope = Rope::new(usize::try_from(args.knots)?, args.max_link)?;
+
+    if args.steps {
+        for (step, rope) in rope.clone().steps(&motions).enumerate() {
+            let knots: Vec<_> = rope.knots.iter().map(ToString::to_string).collect();
+            println!("{:>6}  {}", step + 1, knots.join(" "));
+        }
+        println!();
+    }
+
+    println!("knot  visited");
+    for (knot, visited) in rope.visited_counts(&motions).into_iter().enumerate() {
+        let name = match knot {
+            0 => "head".to_string(),
+            knot => knot.to_string(),
+        };
+        println!("{name:>4}  {visited:>7}");
+    }
+
+    Ok(())
+}
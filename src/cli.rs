@@ -0,0 +1,471 @@
+//! Command line arguments for the `aoc` binary
+
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+/// Cli
+#[derive(Debug, Parser)]
+#[clap(name = "Advent of Code", version, about)]
+pub struct Cli {
+    #[clap(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Solve the puzzles for a day, or every day
+    Run(Run),
+    /// Time parsing and solving each day, optionally against a saved baseline
+    Bench(Bench),
+    /// Check every day's answers against the accepted ones
+    Verify(Verify),
+    /// Rank the elves of a day 1 inventory by the calories they carry
+    Day1(Day1),
+    /// Play a day 2 strategy guide round by round, both ways and at its best
+    Day2(Day2),
+    /// Score day 3 rucksacks, finding the badges of groups of any size
+    Day3(Day3),
+    /// Find the sections day 4 elves clean, share and miss
+    Day4(Day4),
+    /// Rearrange day 5 stacks with a model of crane, optionally drawing each move
+    Day5(Day5),
+    /// Find the markers in a day 6 datastream as it's read, for any window length
+    Day6(Day6),
+    /// Explore the filesystem of a day 7 terminal transcript
+    Day7(Day7),
+    /// Draw the day 8 forest's visible trees, or a heatmap of its scenic scores
+    Day8(Day8),
+    /// Simulate a day 9 rope of any length and slack, counting where each knot goes
+    Day9(Day9),
+}
+
+/// Which of the puzzles to solve
+#[derive(Debug, Args)]
+pub struct Run {
+    /// The day to solve
+    #[clap(short, long, required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+
+    /// Only solve this part, otherwise both are
+    #[clap(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+
+    /// Path to the puzzle input, `-` for stdin, defaults to input/day-N.txt
+    #[clap(short, long, conflicts_with = "all")]
+    pub input: Option<PathBuf>,
+
+    /// Solve every day, reading each from input/day-N.txt
+    #[clap(short, long, conflicts_with = "day")]
+    pub all: bool,
+
+    /// How to print the answers
+    #[clap(short, long, value_enum, default_value_t = Output::Text)]
+    pub output: Output,
+}
+
+/// Which days to time, and what to compare them to
+#[derive(Debug, Args)]
+pub struct Bench {
+    /// Only time this day, otherwise every day is timed
+    #[clap(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+
+    /// Path to the puzzle input for the day, `-` for stdin, defaults to input/day-N.txt
+    #[clap(short, long, requires = "day")]
+    pub input: Option<PathBuf>,
+
+    /// How many times to parse and solve each day
+    #[clap(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    pub iterations: u64,
+
+    /// Save the timings as a baseline to this file
+    #[clap(short, long)]
+    pub save: Option<PathBuf>,
+
+    /// Compare the timings against the baseline in this file
+    #[clap(short, long)]
+    pub baseline: Option<PathBuf>,
+
+    /// Percent a median may be slower than the baseline before it's flagged
+    #[clap(short, long, default_value_t = 10.0)]
+    pub threshold: f64,
+}
+
+/// Which days to check, and the answers to check them against
+#[derive(Debug, Args)]
+pub struct Verify {
+    /// Only check this day, otherwise every day with answers is
+    #[clap(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+
+    /// Path to the accepted answers
+    #[clap(short, long, default_value = "answers.toml")]
+    pub answers: PathBuf,
+}
+
+/// The calorie inventory to rank
+#[derive(Debug, Args)]
+pub struct Day1 {
+    /// Path to the inventory, `-` for stdin, defaults to input/day-1.txt
+    #[clap(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// How many of the elves carrying the most calories to list
+    #[clap(short = 'n', long, default_value_t = 3)]
+    pub top: usize,
+}
+
+/// The strategy guide to audit
+#[derive(Debug, Args)]
+pub struct Day2 {
+    /// Path to the strategy guide, `-` for stdin, defaults to input/day-2.txt
+    #[clap(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// The game the guide is for
+    #[clap(short, long, value_enum, default_value_t = Game::Rps)]
+    pub game: Game,
+}
+
+/// The rucksacks to score, and how many elves are in a group
+#[derive(Debug, Args)]
+pub struct Day3 {
+    /// Path to the rucksacks, `-` for stdin, defaults to input/day-3.txt
+    #[clap(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// How many elves share a badge
+    #[clap(short, long, default_value_t = 3, value_parser = clap::value_parser!(u16).range(1..))]
+    pub group_size: u16,
+}
+
+/// The section assignments to look over, and how many elves make a section shared
+#[derive(Debug, Args)]
+pub struct Day4 {
+    /// Path to the assignments, `-` for stdin, defaults to input/day-4.txt
+    #[clap(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// Report the sections cleaned by more than this many elves
+    #[clap(short = 'k', long, default_value_t = 1)]
+    pub more_than: usize,
+}
+
+/// The stacks and moves to rearrange, and the crane that makes them
+#[derive(Debug, Args)]
+pub struct Day5 {
+    /// Path to the drawing and moves, `-` for stdin, defaults to input/day-5.txt
+    #[clap(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// The model of crane
+    #[clap(short, long, value_enum, default_value_t = CraneModel::CrateMover9000)]
+    pub crane: CraneModel,
+
+    /// The most crates the crane can lift at once, splitting larger lifts
+    #[clap(long, value_parser = clap::value_parser!(u64).range(1..))]
+    pub capacity: Option<u64>,
+
+    /// Draw the stacks after every move
+    #[clap(short, long)]
+    pub trace: bool,
+}
+
+/// The models of crane for moving crates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CraneModel {
+    /// Moves crates one at a time
+    #[value(name = "9000")]
+    CrateMover9000,
+    /// Moves any number of crates at once
+    #[value(name = "9001")]
+    CrateMover9001,
+}
+
+/// The datastream to search, and the length of the window of distinct characters
+#[derive(Debug, Args)]
+pub struct Day6 {
+    /// Path to the datastream, `-` for stdin, defaults to input/day-6.txt
+    #[clap(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// How many distinct characters make a marker, 4 for packets and 14 for messages
+    #[clap(short, long, default_value_t = 4, value_parser = clap::value_parser!(u64).range(1..))]
+    pub window: u64,
+
+    /// List every marker, not just the first
+    #[clap(short, long)]
+    pub all: bool,
+}
+
+/// The transcript to explore, and the disk to free space on
+#[derive(Debug, Args)]
+pub struct Day7 {
+    /// Path to the terminal transcript, `-` for stdin, defaults to input/day-7.txt
+    #[clap(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// Print a transcript of listing this real directory instead
+    #[clap(long, value_name = "DIR", conflicts_with_all = ["input", "materialize"])]
+    pub from_dir: Option<PathBuf>,
+
+    /// Create the transcript's files and directories in a new temporary directory, with sparse
+    /// files of the listed sizes
+    #[clap(long)]
+    pub materialize: bool,
+
+    /// Draw the whole tree of files and directories
+    #[clap(short, long)]
+    pub tree: bool,
+
+    /// How many of the largest directories to list
+    #[clap(short = 'n', long, default_value_t = 5)]
+    pub largest: usize,
+
+    /// The size of the disk
+    #[clap(long, default_value_t = 70000000)]
+    pub disk: usize,
+
+    /// The free space the update needs
+    #[clap(long, default_value_t = 30000000)]
+    pub update: usize,
+}
+
+/// The forest to draw, and how
+#[derive(Debug, Args)]
+pub struct Day8 {
+    /// Path to the tree heights, `-` for stdin, defaults to input/day-8.txt
+    #[clap(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// Draw a heatmap of the scenic scores rather than the visible trees
+    #[clap(long)]
+    pub heatmap: bool,
+
+    /// Draw in color with ANSI escapes
+    #[clap(short, long)]
+    pub color: bool,
+
+    /// Save an image instead, a .png or .ppm file
+    #[clap(long, value_name = "FILE")]
+    pub image: Option<PathBuf>,
+
+    /// The pixels to a tree in the image
+    #[clap(long, default_value_t = 4, value_parser = clap::value_parser!(u64).range(1..=64))]
+    pub scale: u64,
+}
+
+/// The motions to pull a rope through, and the rope
+#[derive(Debug, Args)]
+pub struct Day9 {
+    /// Path to the motions, `-` for stdin, defaults to input/day-9.txt
+    #[clap(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// How many knots the rope has, counting the head
+    #[clap(short, long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    pub knots: u64,
+
+    /// How far apart two knots can be before the one behind is pulled along
+    #[clap(short, long, default_value_t = 1)]
+    pub max_link: usize,
+
+    /// Print where every knot is after each step
+    #[clap(short, long)]
+    pub steps: bool,
+}
+
+/// The games a strategy guide can be for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Game {
+    /// Rock, Paper, Scissors, lettered A to C and X to Z
+    Rps,
+    /// Rock, Paper, Scissors, Spock, Lizard, lettered A to E and V to Z
+    Rpssl,
+}
+
+/// How the answers are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Output {
+    /// Each day's title followed by its answers
+    Text,
+    /// A JSON object per line for each part: day, part, answer, parse_time and solve_time,
+    /// the times in seconds
+    Json,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_args() {
+        let cli = Cli::try_parse_from(["aoc", "run", "--day", "12", "--part", "2"]).unwrap();
+        let Command::Run(run) = cli.command else {
+            panic!("expected run");
+        };
+        assert_eq!(run.day, Some(12));
+        assert_eq!(run.part, Some(2));
+        assert_eq!(run.input, None);
+        assert_eq!(run.output, Output::Text);
+
+        let cli = Cli::try_parse_from(["aoc", "run", "--all", "--output", "json"]).unwrap();
+        let Command::Run(run) = cli.command else {
+            panic!("expected run");
+        };
+        assert!(run.all);
+        assert_eq!(run.output, Output::Json);
+
+        assert!(Cli::try_parse_from(["aoc", "run"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "--day", "1", "--part", "3"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "run", "--all", "--day", "1"]).is_err());
+    }
+
+    #[test]
+    fn test_bench_args() {
+        let cli =
+            Cli::try_parse_from(["aoc", "bench", "-n", "3", "--baseline", "base.json"]).unwrap();
+        let Command::Bench(bench) = cli.command else {
+            panic!("expected bench");
+        };
+        assert_eq!(bench.day, None);
+        assert_eq!(bench.iterations, 3);
+        assert_eq!(bench.baseline, Some(PathBuf::from("base.json")));
+        assert_eq!(bench.threshold, 10.0);
+
+        assert!(Cli::try_parse_from(["aoc", "bench", "--input", "day-1.txt"]).is_err());
+        assert!(Cli::try_parse_from(["aoc", "bench", "-n", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_verify_args() {
+        let cli = Cli::try_parse_from(["aoc", "verify"]).unwrap();
+        let Command::Verify(verify) = cli.command else {
+            panic!("expected verify");
+        };
+        assert_eq!(verify.day, None);
+        assert_eq!(verify.answers, PathBuf::from("answers.toml"));
+
+        let cli = Cli::try_parse_from(["aoc", "verify", "-d", "3", "-a", "other.toml"]).unwrap();
+        let Command::Verify(verify) = cli.command else {
+            panic!("expected verify");
+        };
+        assert_eq!(verify.day, Some(3));
+        assert_eq!(verify.answers, PathBuf::from("other.toml"));
+    }
+
+    #[test]
+    fn test_day_1_args() {
+        let cli = Cli::try_parse_from(["aoc", "day1", "--top", "10", "-i", "-"]).unwrap();
+        let Command::Day1(day1) = cli.command else {
+            panic!("expected day1");
+        };
+        assert_eq!(day1.top, 10);
+        assert_eq!(day1.input, Some(PathBuf::from("-")));
+
+        let cli = Cli::try_parse_from(["aoc", "day1"]).unwrap();
+        let Command::Day1(day1) = cli.command else {
+            panic!("expected day1");
+        };
+        assert_eq!(day1.top, 3);
+    }
+
+    #[test]
+    fn test_day_2_args() {
+        let cli = Cli::try_parse_from(["aoc", "day2", "--game", "rpssl"]).unwrap();
+        let Command::Day2(day2) = cli.command else {
+            panic!("expected day2");
+        };
+        assert_eq!(day2.game, Game::Rpssl);
+        assert_eq!(day2.input, None);
+
+        assert!(Cli::try_parse_from(["aoc", "day2", "--game", "chess"]).is_err());
+    }
+
+    #[test]
+    fn test_day_3_args() {
+        let cli = Cli::try_parse_from(["aoc", "day3", "--group-size", "6"]).unwrap();
+        let Command::Day3(day3) = cli.command else {
+            panic!("expected day3");
+        };
+        assert_eq!(day3.group_size, 6);
+
+        assert!(Cli::try_parse_from(["aoc", "day3", "--group-size", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_day_4_args() {
+        let cli = Cli::try_parse_from(["aoc", "day4", "-k", "2"]).unwrap();
+        let Command::Day4(day4) = cli.command else {
+            panic!("expected day4");
+        };
+        assert_eq!(day4.more_than, 2);
+        assert_eq!(day4.input, None);
+    }
+
+    #[test]
+    fn test_day_5_args() {
+        let cli = Cli::try_parse_from(["aoc", "day5", "--crane", "9001", "--capacity", "3", "-t"])
+            .unwrap();
+        let Command::Day5(day5) = cli.command else {
+            panic!("expected day5");
+        };
+        assert_eq!(day5.crane, CraneModel::CrateMover9001);
+        assert_eq!(day5.capacity, Some(3));
+        assert!(day5.trace);
+
+        assert!(Cli::try_parse_from(["aoc", "day5", "--capacity", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_day_6_args() {
+        let cli = Cli::try_parse_from(["aoc", "day6", "-w", "14", "--all"]).unwrap();
+        let Command::Day6(day6) = cli.command else {
+            panic!("expected day6");
+        };
+        assert_eq!(day6.window, 14);
+        assert!(day6.all);
+
+        assert!(Cli::try_parse_from(["aoc", "day6", "-w", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_day_7_args() {
+        let cli = Cli::try_parse_from(["aoc", "day7", "--tree", "--update", "100"]).unwrap();
+        let Command::Day7(day7) = cli.command else {
+            panic!("expected day7");
+        };
+        assert!(day7.tree);
+        assert_eq!(day7.largest, 5);
+        assert_eq!(day7.disk, 70000000);
+        assert_eq!(day7.update, 100);
+        assert_eq!(day7.from_dir, None);
+
+        assert!(Cli::try_parse_from(["aoc", "day7", "--from-dir", ".", "-i", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_day_8_args() {
+        let cli = Cli::try_parse_from(["aoc", "day8", "--heatmap", "--image", "a.png"]).unwrap();
+        let Command::Day8(day8) = cli.command else {
+            panic!("expected day8");
+        };
+        assert!(day8.heatmap && !day8.color);
+        assert_eq!(day8.image, Some(PathBuf::from("a.png")));
+        assert_eq!(day8.scale, 4);
+    }
+
+    #[test]
+    fn test_day_9_args() {
+        let cli = Cli::try_parse_from(["aoc", "day9", "-k", "2", "--max-link", "3"]).unwrap();
+        let Command::Day9(day9) = cli.command else {
+            panic!("expected day9");
+        };
+        assert_eq!(day9.knots, 2);
+        assert_eq!(day9.max_link, 3);
+        assert!(!day9.steps);
+
+        assert!(Cli::try_parse_from(["aoc", "day9", "--knots", "0"]).is_err());
+    }
+}
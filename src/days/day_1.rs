@@ -0,0 +1,324 @@
+//! --- Day 1: Calorie Counting ---
+//! Santa's reindeer typically eat regular reindeer food, but they need a lot of magical energy to deliver presents on Christmas. For that, their favorite snack is a special type of star fruit that only grows deep in the jungle. The Elves have brought you on their annual expedition to the grove where the fruit grows.
+//!
+//! To supply enough magical energy, the expedition needs to retrieve a minimum of fifty stars by December 25th. Although the Elves assure you that the grove has plenty of fruit, you decide to grab any fruit you see along the way, just in case.
+//!
+//! Collect stars by solving puzzles. Two puzzles will be made available on each day in the Advent calendar; the second puzzle is unlocked when you complete the first. Each puzzle grants one star. Good luck!
+//!
+//! The jungle must be too overgrown and difficult to navigate in vehicles or access from the air; the Elves' expedition traditionally goes on foot. As your boats approach land, the Elves begin taking inventory of their supplies. One important consideration is food - in particular, the number of Calories each Elf is carrying (your puzzle input).
+//!
+//! The Elves take turns writing down the number of Calories contained by the various meals, snacks, rations, etc. that they've brought with them, one item per line. Each Elf separates their own inventory from the previous Elf's inventory (if any) by a blank line.
+//!
+//! For example, suppose the Elves finish writing their items' Calories and end up with the following list:
+//!
+//! 1000
+//! 2000
+//! 3000
+//!
+//! 4000
+//!
+//! 5000
+//! 6000
+//!
+//! 7000
+//! 8000
+//! 9000
+//!
+//! 10000
+//! This list represents the Calories of the food carried by five Elves:
+//!
+//! The first Elf is carrying food with 1000, 2000, and 3000 Calories, a total of 6000 Calories.
+//! The second Elf is carrying one food item with 4000 Calories.
+//! The third Elf is carrying food with 5000 and 6000 Calories, a total of 11000 Calories.
+//! The fourth Elf is carrying food with 7000, 8000, and 9000 Calories, a total of 24000 Calories.
+//! The fifth Elf is carrying one food item with 10000 Calories.
+//! In case the Elves get hungry and need extra snacks, they need to know which Elf to ask: they'd like to know how many Calories are being carried by the Elf carrying the most Calories. In the example above, this is 24000 (carried by the fourth Elf).
+//!
+//! Find the Elf carrying the most Calories. How many total Calories is that Elf carrying?
+//!
+//! --- Part Two ---
+//! By the time you calculate the answer to the Elves' question, they've already realized that the Elf carrying the most Calories of food might eventually run out of snacks.
+//!
+//! To avoid this unacceptable situation, the Elves would instead like to know the total Calories carried by the top three Elves carrying the most Calories. That way, even if one of those Elves runs out of snacks, they still have two backups.
+//!
+//! In the example above, the top three Elves are the fourth Elf (with 24000 Calories), then the third Elf (with 11000 Calories), then the fifth Elf (with 10000 Calories). The sum of the Calories carried by these three elves is 45000.
+//!
+//! Find the top three Elves carrying the most Calories. How many Calories are those Elves carrying in total?
+//!
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::io::BufRead;
+
+use crate::parsing::{self, parse_all, ParseError};
+use crate::solution::{Answer, Solution};
+
+/// One elf's share of the inventory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    /// The elf's place in the inventory, counting from 1
+    pub number: usize,
+    pub calories: usize,
+    /// How many food items the elf carries
+    pub items: usize,
+}
+
+/// Read the inventory, each elf's calories a line at a time with a blank line between elves
+///
+/// Inventories from other tools are accepted too: Windows line endings and trailing whitespace
+/// are ignored, a line of only whitespace separates elves like an empty one, and anything after a
+/// `#` is a comment. A line holding only a comment is skipped without separating elves.
+pub fn parse_elves(reader: impl BufRead) -> Result<Vec<Elf>, Box<dyn Error>> {
+    let mut elves = Vec::<Elf>::new();
+    let mut current = Option::<Elf>::None;
+
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+
+        let (content, is_comment) = match line.split_once('#') {
+            Some((content, _)) => (content, true),
+            None => (line.as_str(), false),
+        };
+
+        // trimming also drops the \r that lines() leaves from a \r\n
+        let entry = content.trim();
+        if entry.is_empty() {
+            if is_comment {
+                continue;
+            }
+
+            if let Some(elf) = current.take() {
+                elves.push(elf);
+            }
+            continue;
+        }
+
+        let indent = content.len() - content.trim_start().len();
+        let value =
+            parse_all(entry, parsing::usize).map_err(|e| e.after(indent).on_line(number + 1))?;
+        let elf = current.get_or_insert(Elf {
+            number: elves.len() + 1,
+            calories: 0,
+            items: 0,
+        });
+        elf.calories = elf.calories.checked_add(value).ok_or_else(|| {
+            ParseError::new(1, "calories small enough to add to the elf's total")
+                .after(indent)
+                .on_line(number + 1)
+        })?;
+        elf.items += 1;
+    }
+
+    if let Some(elf) = current.take() {
+        elves.push(elf);
+    }
+
+    Ok(elves)
+}
+
+/// The `n` elves carrying the most calories, most first
+///
+/// Only `n` elves are kept at a time, in a heap whose smallest is dropped as each better elf
+/// arrives. Of elves carrying the same, the earlier ones are ranked higher.
+pub fn top_elves(elves: impl IntoIterator<Item = Elf>, n: usize) -> Vec<Elf> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    // a min-heap of the best so far, so the worst of them is the one popped
+    let rank = |elf: &Elf| Reverse((elf.calories, Reverse(elf.number)));
+    let mut top = BinaryHeap::with_capacity(n + 1);
+
+    for elf in elves {
+        top.push((rank(&elf), elf.number, elf.items));
+        if top.len() > n {
+            top.pop();
+        }
+    }
+
+    top.into_sorted_vec()
+        .into_iter()
+        .map(|(Reverse((calories, _)), number, items)| Elf {
+            number,
+            calories,
+            items,
+        })
+        .collect()
+}
+
+/// Statistics of the calories the elves carry
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub elves: usize,
+    pub total: usize,
+    pub mean: f64,
+    pub median: f64,
+}
+
+/// Summarize the calories of all the elves, None if there are none
+pub fn summarize(elves: &[Elf]) -> Option<Summary> {
+    if elves.is_empty() {
+        return None;
+    }
+
+    let mut calories: Vec<usize> = elves.iter().map(|elf| elf.calories).collect();
+    calories.sort_unstable();
+
+    let total: usize = calories.iter().sum();
+    let middle = calories.len() / 2;
+    let median = if calories.len().is_multiple_of(2) {
+        (calories[middle - 1] + calories[middle]) as f64 / 2.0
+    } else {
+        calories[middle] as f64
+    };
+
+    Some(Summary {
+        elves: elves.len(),
+        total,
+        mean: total as f64 / elves.len() as f64,
+        median,
+    })
+}
+
+pub struct Inventory {
+    pub elves: Vec<Elf>,
+}
+
+impl Solution for Inventory {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            elves: parse_elves(input.as_bytes())?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        let top = top_elves(self.elves.iter().copied(), 1);
+        Ok(top.first().map_or(0, |elf| elf.calories).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        let top = top_elves(self.elves.iter().copied(), 3);
+        Ok(top.iter().map(|elf| elf.calories).sum::<usize>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+"#;
+
+    #[test]
+    fn test_example_part1() {
+        let inventory = Inventory::parse(INPUT).unwrap();
+        let calories: Vec<_> = inventory.elves.iter().map(|elf| elf.calories).collect();
+
+        assert_eq!(calories, [6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(inventory.part1().unwrap(), Answer::Integer(24000));
+        assert_eq!(
+            Inventory::parse("").unwrap().part1().unwrap(),
+            Answer::Integer(0)
+        );
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let inventory = Inventory::parse(INPUT).unwrap();
+        let top = top_elves(inventory.elves.iter().copied(), 3);
+
+        assert_eq!(top.iter().map(|elf| elf.calories).sum::<usize>(), 45000);
+        assert_eq!(inventory.part2().unwrap(), Answer::Integer(45000));
+    }
+
+    #[test]
+    fn test_top_elves() {
+        let elves = parse_elves(INPUT.as_bytes()).unwrap();
+        let top = top_elves(elves.iter().copied(), 2);
+
+        assert_eq!(
+            top,
+            [
+                Elf {
+                    number: 4,
+                    calories: 24000,
+                    items: 3
+                },
+                Elf {
+                    number: 3,
+                    calories: 11000,
+                    items: 2
+                },
+            ]
+        );
+        assert_eq!(top_elves(elves.iter().copied(), 0), []);
+        assert_eq!(top_elves(elves.iter().copied(), 10).len(), 5);
+
+        // ties go to the earlier elf
+        let tied = top_elves(
+            elves.iter().map(|elf| Elf {
+                calories: 1,
+                ..*elf
+            }),
+            2,
+        );
+        assert_eq!(
+            tied.iter().map(|elf| elf.number).collect::<Vec<_>>(),
+            [1, 2]
+        );
+    }
+
+    #[test]
+    fn test_summarize() {
+        let elves = parse_elves(INPUT.as_bytes()).unwrap();
+        let summary = summarize(&elves).unwrap();
+
+        assert_eq!(summary.elves, 5);
+        assert_eq!(summary.total, 55000);
+        assert_eq!(summary.mean, 11000.0);
+        assert_eq!(summary.median, 10000.0);
+        assert_eq!(summarize(&elves[..4]).unwrap().median, 8500.0);
+        assert_eq!(summarize(&[]), None);
+    }
+
+    #[test]
+    fn test_parse_messy_inventory() {
+        let input = "# exported from the spreadsheet\r\n1000\r\n 2000  # snacks\r\n\t\r\n# elf two\r\n3000\r\n   \r\n\r\n4000";
+        let elves = parse_elves(input.as_bytes()).unwrap();
+
+        let calories: Vec<_> = elves.iter().map(|elf| elf.calories).collect();
+        assert_eq!(calories, [3000, 3000, 4000]);
+        assert_eq!(elves[0].items, 2);
+        assert_eq!(elves[2].number, 3);
+
+        let error = parse_elves("1000\r\n\r\n  12a0\r\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 5: expected the end of the line"
+        );
+
+        let error = parse_elves("1000\nlots\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a number");
+
+        let input = format!("1\n\n{}\n1\n", usize::MAX);
+        let error = parse_elves(input.as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: expected calories small enough to add to the elf's total"
+        );
+    }
+}
@@ -312,38 +312,34 @@
 
 use std::error::Error;
 use std::fmt::Write;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
-use clap::Parser;
+use nom::{
+    branch::alt,
+    combinator::{map, value},
+    sequence::preceded,
+};
 
-const CYCLES: &[usize] = &[20, 60, 100, 140, 180, 220];
-const CRT_ROW_WIDTH: usize = 40;
+use crate::parsing::{self, parse_lines, tag, IResult};
+use crate::solution::{Answer, Solution};
 
-/// Cli
-#[derive(Debug, Parser)]
-#[clap(name = "Advent of Code", version, about)]
-struct Cli {
-    /// Disable INFO messages, WARN and ERROR will remain
-    #[clap(short = 'f', long = "file")]
-    pub(crate) file: String,
-}
+pub const CYCLES: &[usize] = &[20, 60, 100, 140, 180, 220];
+pub const CRT_ROW_WIDTH: usize = 40;
 
 #[derive(Debug, Clone)]
-enum Instruction {
+pub enum Instruction {
     Noop,
     Addx { arg: isize },
 }
 
 impl Instruction {
-    fn to_cycles(&self) -> usize {
+    pub fn to_cycles(&self) -> usize {
         match self {
             Instruction::Noop => 1,
             Instruction::Addx { .. } => 2,
         }
     }
 
-    fn to_value(&self) -> isize {
+    pub fn to_value(&self) -> isize {
         match self {
             Instruction::Noop => 0,
             Instruction::Addx { arg } => *arg,
@@ -351,22 +347,17 @@ impl Instruction {
     }
 }
 
-fn parse_instruction(line: &str) -> Instruction {
-    let mut split = line.split(' ');
-    let instruction = split.next().expect("no instruction");
-
-    match instruction {
-        "noop" => Instruction::Noop,
-        "addx" => {
-            let arg = split.next().expect("no arg");
-            let arg = isize::from_str_radix(arg, 10).expect("bad argument");
+// noop|addx -11
+pub fn parse_instruction(input: &str) -> IResult<'_, Instruction> {
+    alt((
+        value(Instruction::Noop, tag("noop")),
+        map(preceded(tag("addx "), parsing::isize), |arg| {
             Instruction::Addx { arg }
-        }
-        _ => panic!("unknown instruction {instruction}"),
-    }
+        }),
+    ))(input)
 }
 
-fn signal_strength(reader: impl BufRead, cycles: &[usize]) -> Result<isize, Box<dyn Error>> {
+pub fn signal_strength(program: &[Instruction], cycles: &[usize]) -> isize {
     let mut register = 1_isize;
     let mut running_signal_strength = 0_isize;
     let mut cycles = cycles.iter();
@@ -374,13 +365,7 @@ fn signal_strength(reader: impl BufRead, cycles: &[usize]) -> Result<isize, Box<
     let mut cycle_count = 0;
     let mut view_cycle = *cycles.next().unwrap();
 
-    for line in reader.lines() {
-        let line = line?;
-        if line.is_empty() {
-            continue;
-        }
-
-        let instruction = parse_instruction(&line);
+    for instruction in program {
         cycle_count += instruction.to_cycles();
 
         if cycle_count >= view_cycle {
@@ -394,45 +379,27 @@ fn signal_strength(reader: impl BufRead, cycles: &[usize]) -> Result<isize, Box<
         register += instruction.to_value();
     }
 
-    Ok(running_signal_strength)
+    running_signal_strength
 }
 
-fn render_image(reader: impl BufRead, crt_rows: usize) -> Result<String, Box<dyn Error>> {
+pub fn render_image(program: &[Instruction], crt_rows: usize) -> Result<String, Box<dyn Error>> {
     let mut register = 1_isize;
     let mut rendered_image = String::new();
-    let mut crt_rows = (1..=crt_rows).into_iter().map(|row| row * 40);
+    let mut crt_rows = (1..=crt_rows).map(|row| row * CRT_ROW_WIDTH);
 
     let mut cycle_count = 0_usize;
     let mut row_end = crt_rows.next().unwrap();
     let mut current_pixels = String::new();
 
-    for line in reader.lines() {
-        let line = line?;
-        if line.is_empty() {
-            continue;
-        }
-
-        let instruction = parse_instruction(&line);
-
+    for instruction in program {
         for _ in 0..instruction.to_cycles() {
             let pixel = (cycle_count % CRT_ROW_WIDTH) as isize;
-            if register.abs_diff(pixel as isize) <= 1 {
+            if register.abs_diff(pixel) <= 1 {
                 current_pixels.push('#');
             } else {
                 current_pixels.push('.')
             }
 
-            // debug only
-            let mut current_sprite = String::new();
-
-            for i in 0..CRT_ROW_WIDTH {
-                if register.abs_diff(i as isize) <= 1 {
-                    current_sprite.push('#');
-                } else {
-                    current_sprite.push('.');
-                }
-            }
-
             cycle_count += 1;
             if cycle_count >= row_end {
                 writeln!(&mut rendered_image, "{current_pixels}")?;
@@ -451,23 +418,25 @@ fn render_image(reader: impl BufRead, crt_rows: usize) -> Result<String, Box<dyn
     Ok(rendered_image)
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    println!("{}", env!("CARGO_PKG_NAME"));
-    let args = Cli::parse();
-
-    let filename = &args.file;
+/// The instructions of the program for the CPU
+pub struct Program {
+    pub instructions: Vec<Instruction>,
+}
 
-    let reader = BufReader::new(File::open(filename)?);
-    let strength = signal_strength(reader, CYCLES)?;
+impl Solution for Program {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        let instructions = parse_lines(input.as_bytes(), parse_instruction)?;
 
-    println!("part 1 strength: {strength}");
+        Ok(Self { instructions })
+    }
 
-    let reader = BufReader::new(File::open(filename)?);
-    let image = render_image(reader, 6)?;
-    println!("part 2, the image");
-    println!("{image}");
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(signal_strength(&self.instructions, CYCLES).into())
+    }
 
-    Ok(())
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(Answer::lines(&render_image(&self.instructions, 6)?))
+    }
 }
 
 #[cfg(test)]
@@ -626,7 +595,7 @@ noop
     #[test]
     fn test_example_part1() {
         assert_eq!(
-            signal_strength(BufReader::new(INPUT.as_bytes()), CYCLES).unwrap(),
+            signal_strength(&Program::parse(INPUT).unwrap().instructions, CYCLES),
             13140
         );
     }
@@ -643,7 +612,7 @@ noop
     #[test]
     fn test_example_part2() {
         assert_eq!(
-            render_image(BufReader::new(INPUT.as_bytes()), 6)
+            render_image(&Program::parse(INPUT).unwrap().instructions, 6)
                 .unwrap()
                 .trim(),
             EXAMPLE_RENDER.trim()
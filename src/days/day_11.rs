@@ -295,39 +295,29 @@
 //! Worry levels are no longer divided by three after each item is inspected; you'll need to find another way to keep your worry levels manageable. Starting again from the initial state in your puzzle input, what is the level of monkey business after 10000 rounds?
 
 use std::error::Error;
-use std::fmt::Write;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
-use clap::Parser;
 use nom::{
-    bytes::complete::tag,
+    branch::alt,
     character::{
         self,
-        complete::{alphanumeric1, anychar, multispace0, newline, space1},
+        complete::{multispace0, newline, space1},
     },
-    combinator::{map, opt},
+    combinator::{map, opt, value},
     multi::{fold_many0, many1},
     sequence::{pair, preceded, tuple},
-    IResult,
 };
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
 
-/// Cli
-#[derive(Debug, Parser)]
-#[clap(name = "Advent of Code", version, about)]
-struct Cli {
-    /// Disable INFO messages, WARN and ERROR will remain
-    #[clap(short = 'f', long = "file")]
-    pub(crate) file: String,
-}
+use crate::parsing::{self, parse_all, tag, Expected, IResult, ParseError};
+use crate::solution::{Answer, Solution};
 
-struct Item {
-    worry: usize,
+#[derive(Clone)]
+pub struct Item {
+    pub worry: usize,
 }
 
 impl Item {
-    fn calculate_new_worry(&mut self, anxiety: &Instruction, reduce_anxiety: bool) {
+    pub fn calculate_new_worry(&mut self, anxiety: &Instruction, reduce_anxiety: bool) {
         // raise the anxiety
         let mut new_worry = anxiety.inspection_score(&self.worry);
         // wow, it's still ok, divide by 3
@@ -339,27 +329,28 @@ impl Item {
     }
 }
 
-struct Monkey {
-    id: usize,
-    inspected_items_count: usize,
-    items: Vec<Item>,
-    anxiety: Instruction,
-    test: Test,
+#[derive(Clone)]
+pub struct Monkey {
+    pub id: usize,
+    pub inspected_items_count: usize,
+    pub items: Vec<Item>,
+    pub anxiety: Instruction,
+    pub test: Test,
 }
 
 //   Starting items: 79, 98
-fn parse_items(input: &str) -> IResult<&str, Vec<usize>> {
+pub fn parse_items(input: &str) -> IResult<'_, Vec<usize>> {
     let (input, _) = space1(input)?;
     let (input, _) = tag("Starting items: ")(input)?;
 
     let (input, items) = fold_many0(
         pair(
-            character::complete::u32,
+            parsing::usize,
             opt(pair(character::complete::char(','), space1)),
         ),
         Vec::new,
         |mut items, (item, _)| {
-            items.push(item as usize);
+            items.push(item);
             items
         },
     )(input)?;
@@ -369,27 +360,27 @@ fn parse_items(input: &str) -> IResult<&str, Vec<usize>> {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum Operation {
+pub enum Operation {
     Multiply,
     Sum,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Instruction {
-    arg1: Literal,
-    op: Operation,
-    arg2: Literal,
+pub struct Instruction {
+    pub arg1: Literal,
+    pub op: Operation,
+    pub arg2: Literal,
 }
 
 impl Instruction {
-    fn inspection_score(&self, worry: &usize) -> usize {
+    pub fn inspection_score(&self, worry: &usize) -> usize {
         let arg1 = match self.arg1 {
-            Literal::Num(val) => usize::from(val),
-            Literal::Old => worry.clone(),
+            Literal::Num(val) => val,
+            Literal::Old => *worry,
         };
         let arg2 = match self.arg2 {
-            Literal::Num(val) => usize::from(val),
-            Literal::Old => worry.clone(),
+            Literal::Num(val) => val,
+            Literal::Old => *worry,
         };
 
         match self.op {
@@ -400,60 +391,71 @@ impl Instruction {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum Literal {
+pub enum Literal {
     Old,
     Num(usize),
 }
 
 // old|{number}
-fn parse_literal(input: &str) -> IResult<&str, Literal> {
-    if let Some((input, _)) = tag::<_, _, nom::error::Error<&str>>("old")(input).ok() {
-        return Ok((input, Literal::Old));
-    }
+pub fn parse_literal(input: &str) -> IResult<'_, Literal> {
+    alt((
+        value(Literal::Old, tag("old")),
+        map(parsing::usize, Literal::Num),
+    ))(input)
+}
 
-    character::complete::u32(input).map(|(input, val)| (input, Literal::Num(val as usize)))
+// +|*
+pub fn parse_operation(input: &str) -> IResult<'_, Operation> {
+    alt((
+        value(Operation::Sum, character::complete::char('+')),
+        value(Operation::Multiply, character::complete::char('*')),
+    ))(input)
 }
 
 //   Operation: new = old * 19
-fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+pub fn parse_instruction(input: &str) -> IResult<'_, Instruction> {
     let (input, _) = space1(input)?;
     let (input, _) = tag("Operation:")(input)?;
 
     let (input, _) = preceded(space1, tag("new"))(input)?;
     let (input, _) = tuple((space1, character::complete::char('='), space1))(input)?;
 
-    let (input, var1) = alphanumeric1(input)?;
-    let (input, operation) = preceded(space1, anychar)(input)?;
-    let (input, var2) = preceded(space1, alphanumeric1)(input)?;
+    let (input, arg1) = parse_literal(input)?;
+    let (input, op) = preceded(space1, parse_operation)(input)?;
+    let (input, arg2) = preceded(space1, parse_literal)(input)?;
     let (input, _) = opt(newline)(input)?;
 
-    let arg1 = parse_literal(var1)?.1;
-    let arg2 = parse_literal(var2)?.1;
-
-    let op = match operation {
-        '+' => Operation::Sum,
-        '*' => Operation::Multiply,
-        _ => panic!("unrecognized operation: {operation}"),
-    };
-
     Ok((input, Instruction { arg1, op, arg2 }))
 }
 
-struct Test {
-    divisor: usize,
-    true_monkey: usize,
-    false_monkey: usize,
+#[derive(Clone)]
+pub struct Test {
+    pub divisor: usize,
+    pub true_monkey: usize,
+    pub false_monkey: usize,
+}
+
+// 23, which can't be 0 as every worry level would be divided by it
+fn parse_divisor(input: &str) -> IResult<'_, usize> {
+    match parsing::usize(input)? {
+        (rest, divisor) if divisor > 0 => Ok((rest, divisor)),
+        // a failure, so the monkeys before this one aren't taken as all of the notes
+        _ => Err(nom::Err::Failure(Expected {
+            input,
+            expected: "a divisor above 0".to_string(),
+        })),
+    }
 }
 
 //   Test: divisible by 23
 //     If true: throw to monkey 2
 //     If false: throw to monkey 3
-fn parse_test(input: &str) -> IResult<&str, Test> {
+pub fn parse_test(input: &str) -> IResult<'_, Test> {
     let (input, _) = preceded(space1, tag("Test:"))(input)?;
     let (input, _) = preceded(space1, tag("divisible"))(input)?;
     let (input, _) = preceded(space1, tag("by"))(input)?;
     let (input, _) = space1(input)?;
-    let (input, divisor) = map(character::complete::u32, |val| val as usize)(input)?;
+    let (input, divisor) = parse_divisor(input)?;
     let (input, _) = newline(input)?;
 
     let (input, _) = preceded(space1, tag("If"))(input)?;
@@ -462,7 +464,7 @@ fn parse_test(input: &str) -> IResult<&str, Test> {
     let (input, _) = preceded(space1, tag("to"))(input)?;
     let (input, _) = preceded(space1, tag("monkey"))(input)?;
     let (input, _) = space1(input)?;
-    let (input, true_monkey) = map(character::complete::u32, |val| val as usize)(input)?;
+    let (input, true_monkey) = parsing::usize(input)?;
     let (input, _) = newline(input)?;
 
     let (input, _) = preceded(space1, tag("If"))(input)?;
@@ -471,7 +473,7 @@ fn parse_test(input: &str) -> IResult<&str, Test> {
     let (input, _) = preceded(space1, tag("to"))(input)?;
     let (input, _) = preceded(space1, tag("monkey"))(input)?;
     let (input, _) = space1(input)?;
-    let (input, false_monkey) = map(character::complete::u32, |val| val as usize)(input)?;
+    let (input, false_monkey) = parsing::usize(input)?;
     let (input, _) = opt(newline)(input)?;
 
     Ok((
@@ -490,11 +492,11 @@ fn parse_test(input: &str) -> IResult<&str, Test> {
 //   Test: divisible by 23
 //     If true: throw to monkey 2
 //     If false: throw to monkey 3
-fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
+pub fn parse_monkey(input: &str) -> IResult<'_, Monkey> {
     let (input, _) = multispace0(input)?;
     let (input, _) = tag("Monkey")(input)?;
     let (input, _) = space1(input)?;
-    let (input, id) = character::complete::u32(input)?;
+    let (input, id) = parsing::usize(input)?;
     let (input, _) = character::complete::char(':')(input)?;
     let (input, _) = newline(input)?;
 
@@ -502,13 +504,7 @@ fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
     let (input, anxiety) = parse_instruction(input)?;
     let (input, test) = parse_test(input)?;
 
-    let id = id as usize;
-    let items = items
-        .into_iter()
-        .map(|worry| Item {
-            worry: usize::from(worry),
-        })
-        .collect();
+    let items = items.into_iter().map(|worry| Item { worry }).collect();
 
     Ok((
         input,
@@ -522,8 +518,10 @@ fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
     ))
 }
 
-fn parse_monkeys(input: &str) -> (Vec<Monkey>, Vec<usize>) {
-    let (_input, monkeys) = many1(parse_monkey)(input).expect("failed to parse monkeys");
+pub fn parse_monkeys(input: &str) -> Result<(Vec<Monkey>, Vec<usize>), ParseError> {
+    let monkeys = parse_all(input, many1(parse_monkey))?;
+    check_ids(input)?;
+    check_targets(input, monkeys.len())?;
 
     // collect all the primes used
     let primes = monkeys
@@ -531,10 +529,53 @@ fn parse_monkeys(input: &str) -> (Vec<Monkey>, Vec<usize>) {
         .map(|monkey| monkey.test.divisor)
         .collect::<Vec<_>>();
 
-    (monkeys, primes)
+    Ok((monkeys, primes))
 }
 
-fn monkey_business(monkeys: &mut [Monkey], rounds: usize, reduce_anxiety: bool) {
+/// Check the monkeys are numbered in order from 0, as they're found by their place in the notes
+fn check_ids(input: &str) -> Result<(), ParseError> {
+    const MONKEY: &str = "Monkey ";
+
+    let mut next = 0;
+    for (n, line) in input.lines().enumerate() {
+        let header = line.trim_start();
+        let Some(id) = header.strip_prefix(MONKEY) else {
+            continue;
+        };
+
+        let id = id.trim_end().trim_end_matches(':');
+        if id.parse::<usize>().is_ok_and(|id| id != next) {
+            let column = line.len() - header.len() + MONKEY.len() + 1;
+            return Err(
+                ParseError::new(column, format!("monkey {next}, the next in order")).on_line(n + 1),
+            );
+        }
+        next += 1;
+    }
+
+    Ok(())
+}
+
+/// Check every monkey throws to one of the `count` monkeys, pointing at the first that doesn't
+fn check_targets(input: &str, count: usize) -> Result<(), ParseError> {
+    const THROW: &str = "throw to monkey ";
+
+    for (n, line) in input.lines().enumerate() {
+        let Some((before, target)) = line.trim_end().split_once(THROW) else {
+            continue;
+        };
+
+        if target.parse::<usize>().is_ok_and(|target| target >= count) {
+            let column = before.chars().count() + THROW.len() + 1;
+            let expected = format!("a monkey from 0 to {}", count - 1);
+            return Err(ParseError::new(column, expected).on_line(n + 1));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn monkey_business(monkeys: &mut [Monkey], rounds: usize, reduce_anxiety: bool) {
     // rounds
     for _ in 0..rounds {
         // (target index, item worry score)
@@ -556,7 +597,7 @@ fn monkey_business(monkeys: &mut [Monkey], rounds: usize, reduce_anxiety: bool)
                     .map(|mut item| {
                         item.calculate_new_worry(anxiety, reduce_anxiety);
 
-                        if &item.worry % test.divisor == 0 {
+                        if item.worry % test.divisor == 0 {
                             (monkey.test.true_monkey, item)
                         } else {
                             (monkey.test.false_monkey, item)
@@ -575,7 +616,7 @@ fn monkey_business(monkeys: &mut [Monkey], rounds: usize, reduce_anxiety: bool)
     }
 }
 
-fn calculate_top_monkey_bussiness(monkeys: &[Monkey]) -> usize {
+pub fn calculate_top_monkey_bussiness(monkeys: &[Monkey]) -> usize {
     let mut scores = monkeys
         .iter()
         .map(|monkey| monkey.inspected_items_count)
@@ -585,31 +626,31 @@ fn calculate_top_monkey_bussiness(monkeys: &[Monkey]) -> usize {
     scores.iter().rev().take(2).product()
 }
 
-struct MonkeyPrimes(Vec<usize>);
+pub struct MonkeyPrimes(pub Vec<usize>);
 
-struct WideItem(Vec<usize>);
+pub struct WideItem(pub Vec<usize>);
 
 impl WideItem {
-    fn new(worry: usize, primes: &MonkeyPrimes) -> Self {
+    pub fn new(worry: usize, primes: &MonkeyPrimes) -> Self {
         Self(primes.0.iter().map(|prime| worry % prime).collect())
     }
 
-    fn calculate_new_worry(&mut self, anxiety: &Instruction, constraints: &MonkeyPrimes) {
+    pub fn calculate_new_worry(&mut self, anxiety: &Instruction, constraints: &MonkeyPrimes) {
         for (worry, prime) in self.0.iter_mut().zip(constraints.0.iter()) {
             *worry = anxiety.inspection_score(worry) % prime;
         }
     }
 }
 
-struct SmartMonkey {
-    id: usize,
-    inspected_items_count: usize,
-    items: Vec<WideItem>,
-    anxiety: Instruction,
-    test: Test,
+pub struct SmartMonkey {
+    pub id: usize,
+    pub inspected_items_count: usize,
+    pub items: Vec<WideItem>,
+    pub anxiety: Instruction,
+    pub test: Test,
 }
 
-fn make_smart_monkeys(monkeys: Vec<Monkey>, primes: &MonkeyPrimes) -> Vec<SmartMonkey> {
+pub fn make_smart_monkeys(monkeys: Vec<Monkey>, primes: &MonkeyPrimes) -> Vec<SmartMonkey> {
     monkeys
         .into_iter()
         .map(|monkey| SmartMonkey {
@@ -626,7 +667,7 @@ fn make_smart_monkeys(monkeys: Vec<Monkey>, primes: &MonkeyPrimes) -> Vec<SmartM
         .collect()
 }
 
-fn smart_monkey_business(monkeys: &mut [SmartMonkey], rounds: usize, primes: &MonkeyPrimes) {
+pub fn smart_monkey_business(monkeys: &mut [SmartMonkey], rounds: usize, primes: &MonkeyPrimes) {
     // rounds
     for _r in 0..rounds {
         // (target index, item worry score)
@@ -666,7 +707,7 @@ fn smart_monkey_business(monkeys: &mut [SmartMonkey], rounds: usize, primes: &Mo
     }
 }
 
-fn calculate_smart_monkey_bussiness(monkeys: &[SmartMonkey]) -> usize {
+pub fn calculate_smart_monkey_bussiness(monkeys: &[SmartMonkey]) -> usize {
     let mut scores = monkeys
         .iter()
         .map(|monkey| monkey.inspected_items_count)
@@ -676,40 +717,33 @@ fn calculate_smart_monkey_bussiness(monkeys: &[SmartMonkey]) -> usize {
     scores.iter().rev().take(2).product()
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    println!("{}", env!("CARGO_PKG_NAME"));
-    let args = Cli::parse();
-
-    let filename = &args.file;
+/// The notes on each monkey, and the divisors they test worry levels with
+pub struct Notes {
+    pub monkeys: Vec<Monkey>,
+    pub primes: Vec<usize>,
+}
 
-    let reader = BufReader::new(File::open(filename)?);
-    let mut input = String::new();
+impl Solution for Notes {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        let (monkeys, primes) = parse_monkeys(input)?;
 
-    for line in reader.lines() {
-        let line = line?;
-        if line.is_empty() {
-            continue;
-        }
-
-        writeln!(input, "{line}")?;
+        Ok(Self { monkeys, primes })
     }
 
-    // got all the monkeys
-    let (mut monkeys, _primes) = parse_monkeys(&input);
-    monkey_business(&mut monkeys, 20, true);
-
-    let total_mb = calculate_top_monkey_bussiness(&monkeys);
-    println!("part 1, monkey business product: {total_mb}");
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        let mut monkeys = self.monkeys.clone();
+        monkey_business(&mut monkeys, 20, true);
 
-    let (monkeys, primes) = parse_monkeys(&input);
-    let primes = MonkeyPrimes(primes);
-    let mut monkeys = make_smart_monkeys(monkeys, &primes);
-    smart_monkey_business(&mut monkeys, 10_000, &primes);
+        Ok(calculate_top_monkey_bussiness(&monkeys).into())
+    }
 
-    let total_mb = calculate_smart_monkey_bussiness(&monkeys);
-    println!("part 2, monkey business product: {total_mb}");
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        let primes = MonkeyPrimes(self.primes.clone());
+        let mut monkeys = make_smart_monkeys(self.monkeys.clone(), &primes);
+        smart_monkey_business(&mut monkeys, 10_000, &primes);
 
-    Ok(())
+        Ok(calculate_smart_monkey_bussiness(&monkeys).into())
+    }
 }
 
 #[cfg(test)]
@@ -766,7 +800,13 @@ Monkey 3:
                 op: Operation::Multiply,
                 arg2: Literal::Num(19),
             }
-        )
+        );
+
+        let input = "  Operation: new = old / 19";
+        assert_eq!(
+            parse_all(input, parse_instruction).unwrap_err(),
+            ParseError::new(24, "'+' or '*'")
+        );
     }
 
     #[test]
@@ -780,9 +820,43 @@ Monkey 3:
         .unwrap();
     }
 
+    #[test]
+    fn test_bad_tests() {
+        let input = INPUT.replace("divisible by 13", "divisible by 0");
+        assert_eq!(
+            parse_monkeys(&input).err(),
+            Some(ParseError {
+                line: 19,
+                column: 22,
+                expected: "a divisor above 0".to_string()
+            })
+        );
+
+        let input = INPUT.replace("If false: throw to monkey 0", "If false: throw to monkey 4");
+        assert_eq!(
+            parse_monkeys(&input).err(),
+            Some(ParseError {
+                line: 14,
+                column: 31,
+                expected: "a monkey from 0 to 3".to_string()
+            })
+        );
+
+        let input = INPUT.replace("Monkey 2:", "Monkey 5:");
+        assert_eq!(
+            parse_monkeys(&input).err(),
+            Some(ParseError {
+                line: 16,
+                column: 8,
+                expected: "monkey 2, the next in order".to_string()
+            })
+        );
+        assert!(parse_monkeys(&INPUT[INPUT.find("Monkey 1").unwrap()..]).is_err());
+    }
+
     #[test]
     fn test_part1_input() {
-        let (mut monkeys, _primes) = parse_monkeys(INPUT);
+        let (mut monkeys, _primes) = parse_monkeys(INPUT).unwrap();
 
         monkey_business(&mut monkeys, 20, true);
 
@@ -796,7 +870,7 @@ Monkey 3:
 
     #[test]
     fn test_part2_input() {
-        let (monkeys, primes) = parse_monkeys(INPUT);
+        let (monkeys, primes) = parse_monkeys(INPUT).unwrap();
         let primes = MonkeyPrimes(primes);
         let mut monkeys = make_smart_monkeys(monkeys, &primes);
 
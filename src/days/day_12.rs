@@ -17,7 +17,7 @@
 //! Here, you start in the top-left corner; your goal is near the middle. You could start by moving down or right, but eventually you'll need to head toward the e at the bottom. From there, you can spiral around to the goal:
 //!
 //! v..v<<<<
-//! >v.vv<<^
+//! \>v.vv<<^
 //! .>vv>E^^
 //! ..v>>>^^
 //! ..>>>>>^
@@ -45,7 +45,7 @@
 //! ...vv<<^
 //! ...v>E^^
 //! .>v>>>^^
-//! >^>>>>>^
+//! \>^>>>>>^
 //! This path reaches the goal in only 29 steps, the fewest possible.
 //!
 //! What is the fewest steps required to move starting from any square with elevation a to the location that should get the best signal?
@@ -57,15 +57,13 @@ use std::{
     cmp::Ordering,
     collections::HashSet,
     error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
+    io::BufRead,
     rc::Rc,
     sync::mpsc::{self, channel, Receiver, Sender},
     thread::current,
     time::Duration,
 };
 
-use clap::Parser;
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
     execute,
@@ -83,62 +81,17 @@ use tui::{
     Terminal,
 };
 
-/// Cli
-#[derive(Debug, Parser)]
-#[clap(name = "Advent of Code", version, about)]
-struct Cli {
-    /// Disable INFO messages, WARN and ERROR will remain
-    #[clap(short = 'f', long = "file")]
-    pub(crate) file: String,
-}
-
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-impl std::fmt::Display for Point {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({},{})", self.x, self.y)
-    }
-}
-
-impl Point {
-    fn up(&self) -> Option<Point> {
-        Some(Point {
-            x: self.x,
-            y: self.y + 1,
-        })
-    }
-
-    fn down(&self) -> Option<Point> {
-        Some(Point {
-            x: self.x,
-            y: self.y.checked_sub(1)?,
-        })
-    }
-
-    fn left(&self) -> Option<Point> {
-        Some(Point {
-            x: self.x.checked_sub(1)?,
-            y: self.y,
-        })
-    }
-
-    fn right(&self) -> Option<Point> {
-        Some(Point {
-            x: self.x + 1,
-            y: self.y,
-        })
-    }
-}
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
 
 #[derive(Clone)]
-struct Grid {
-    start: Point,
-    end: Point,
-    data: Vec<Vec<u8>>,
+pub struct Heightmap {
+    pub start: Point<usize>,
+    pub end: Point<usize>,
+    /// The height of each square, a to z, with S and E already replaced by a and z
+    pub heights: Grid<u8>,
 }
 
 fn get_height(ch: u8) -> u8 {
@@ -149,34 +102,21 @@ fn get_height(ch: u8) -> u8 {
     }
 }
 
-impl Grid {
-    // warning, this only returns the heigh, S and E will be replaces with a and z respectively
-    fn get(&self, point: &Point) -> Option<u8> {
-        self.data
-            .get(point.y)
-            .and_then(|row| row.get(point.x))
-            .cloned()
-            .map(get_height)
+impl Heightmap {
+    pub fn get(&self, point: &Point<usize>) -> Option<u8> {
+        self.heights.get(*point).copied()
     }
 
-    fn all_of(&self, find_ch: u8) -> impl IntoIterator<Item = Point> + '_ {
-        self.data
+    pub fn all_of(&self, find_ch: u8) -> impl IntoIterator<Item = Point<usize>> + '_ {
+        self.heights
             .iter()
-            .enumerate()
-            .flat_map(|(y, row)| {
-                row.iter()
-                    .enumerate()
-                    .map(move |(x, ch)| (Point { x, y }, *ch))
-            })
-            .map(|(point, ch)| (point, get_height(ch)))
-            .filter(move |(_, ch)| *ch == find_ch)
+            .filter(move |(_, ch)| **ch == find_ch)
             .map(|(point, _)| point)
     }
 
-    fn successors(&self, point: &Point) -> Vec<Point> {
-        [point.up(), point.down(), point.left(), point.right()]
-            .into_iter()
-            .filter_map(|next| next)
+    pub fn successors(&self, point: &Point<usize>) -> Vec<Point<usize>> {
+        point
+            .cardinal_neighbors()
             // remove any nodes that aren't in the grid or are too high
             .filter(|next| {
                 if let Some(next_ch) = self.get(next) {
@@ -189,19 +129,19 @@ impl Grid {
             .collect::<Vec<_>>()
     }
 
-    fn distance_from_end(&self, point: &Point) -> usize {
-        self.end.x.abs_diff(point.x) + self.end.y.abs_diff(point.y)
+    pub fn distance_from_end(&self, point: &Point<usize>) -> usize {
+        self.end.manhattan_distance(point)
     }
 
-    fn is_end(&self, point: &Point) -> bool {
+    pub fn is_end(&self, point: &Point<usize>) -> bool {
         self.end == *point
     }
 
     #[allow(unused)]
-    fn find_shortest_path_a_star(
+    pub fn find_shortest_path_a_star(
         &self,
-        start: Point, /* , sender: Sender<Point>*/
-    ) -> Option<Vec<Point>> {
+        start: Point<usize>, /* , sender: Sender<Point<usize>>*/
+    ) -> Option<Vec<Point<usize>>> {
         astar(
             &start,
             |point| self.successors(point).into_iter().map(|p| (p, 1)),
@@ -215,7 +155,7 @@ impl Grid {
     }
 
     #[allow(unused)]
-    fn find_shortest_path_dijkstra(&self) -> Option<Vec<Point>> {
+    pub fn find_shortest_path_dijkstra(&self) -> Option<Vec<Point<usize>>> {
         dijkstra(
             &self.start,
             |point| self.successors(point).into_iter().map(|p| (p, 1)),
@@ -225,7 +165,7 @@ impl Grid {
     }
 
     #[allow(unused)]
-    fn find_shortest_path_bfs(&self) -> Option<Vec<Point>> {
+    pub fn find_shortest_path_bfs(&self) -> Option<Vec<Point<usize>>> {
         bfs(
             &self.start,
             |point| self.successors(point),
@@ -234,9 +174,9 @@ impl Grid {
     }
 
     #[allow(unused)]
-    fn find_shortest_path(&self, sender: Sender<Point>) -> Vec<Point> {
+    pub fn find_shortest_path(&self, sender: Sender<Point<usize>>) -> Vec<Point<usize>> {
         let to_search = Vec::new();
-        let bad_path: Rc<RefCell<HashSet<Point>>> = Rc::new(RefCell::new(HashSet::new()));
+        let bad_path: Rc<RefCell<HashSet<Point<usize>>>> = Rc::new(RefCell::new(HashSet::new()));
         let shortest_path: Rc<RefCell<usize>> = Rc::new(RefCell::new(usize::MAX));
 
         self.search_from(self.start, to_search, bad_path, shortest_path, sender)
@@ -246,12 +186,12 @@ impl Grid {
     #[allow(unused)]
     fn search_from(
         &self,
-        current: Point,
-        mut path: Vec<Point>,
-        visited: Rc<RefCell<HashSet<Point>>>,
+        current: Point<usize>,
+        mut path: Vec<Point<usize>>,
+        visited: Rc<RefCell<HashSet<Point<usize>>>>,
         shortest_path: Rc<RefCell<usize>>,
-        sender: Sender<Point>,
-    ) -> Option<Vec<Point>> {
+        sender: Sender<Point<usize>>,
+    ) -> Option<Vec<Point<usize>>> {
         sender.send(current).unwrap();
         path.push(current);
         if self.end == current {
@@ -264,20 +204,13 @@ impl Grid {
             return None;
         }
 
-        let next = &[
-            current.up(),
-            current.down(),
-            current.left(),
-            current.right(),
-        ];
-
-        let mut next: Vec<Point> = next.into_iter().filter_map(|next| next.clone()).collect();
+        let mut next: Vec<Point<usize>> = current.cardinal_neighbors().collect();
 
         // we'll order our search to go towards the end
         next.sort_unstable_by(|n1, n2| {
-            if visited.borrow().contains(&n1) && !visited.borrow().contains(&n2) {
+            if visited.borrow().contains(n1) && !visited.borrow().contains(n2) {
                 return Ordering::Greater;
-            } else if !visited.borrow().contains(&n1) && visited.borrow().contains(&n2) {
+            } else if !visited.borrow().contains(n1) && visited.borrow().contains(n2) {
                 return Ordering::Less;
             }
 
@@ -320,7 +253,7 @@ impl Grid {
 
         visited.borrow_mut().insert(current);
 
-        let mut found_path: Option<Vec<Point>> = None;
+        let mut found_path: Option<Vec<Point<usize>>> = None;
         for next in next.into_iter() {
             if let Some(ch) = self.get(&next) {
                 // height difference of 1 or less
@@ -376,123 +309,60 @@ impl Grid {
     }
 }
 
-fn parse_grid(reader: impl BufRead) -> Result<Grid, Box<dyn Error>> {
-    let mut rows: Vec<Vec<u8>> = reader
-        .lines()
-        .map(|s| s.expect("bad_data"))
-        .filter(|s| !s.is_empty())
-        .map(|s| s.into_bytes())
-        .collect();
-
-    // organize such that the bottom left of the grid is 0,0
-    rows.reverse();
-
-    // find the start and end
-    let start_and_end = rows
-        .iter()
-        .enumerate()
-        .flat_map(|(y, row)| {
-            row.iter()
-                .enumerate()
-                .map(move |(x, ch)| (Point { x, y }, *ch))
-        })
-        .filter(|(_, ch)| *ch == b'S' || *ch == b'E');
-
-    let mut start = None;
-    let mut end = None;
-
-    for (point, ch) in start_and_end {
-        match ch {
-            b'S' => start = Some(point),
-            b'E' => end = Some(point),
-            _ => panic!("unexpected character: {ch}"),
-        }
-    }
-
-    println!(
-        "S{} E{} {}*{}",
-        start.unwrap(),
-        end.unwrap(),
-        rows[0].len(),
-        rows.len()
-    );
-
-    Ok(Grid {
-        start: start.expect("start not found"),
-        end: end.expect("end not found"),
-        data: rows,
+pub fn parse_grid(reader: impl BufRead) -> Result<Heightmap, Box<dyn Error>> {
+    let squares = Grid::parse(reader, "a height from a to z, S or E", |ch| {
+        matches!(ch, b'a'..=b'z' | b'S' | b'E').then_some(ch)
+    })?;
+
+    // neither was found by the end of the input
+    let missing = |expected| ParseError::new(1, expected).on_line(squares.height() + 1);
+
+    let start = squares
+        .position(|&ch| ch == b'S')
+        .ok_or_else(|| missing("the start, S"))?;
+    let end = squares
+        .position(|&ch| ch == b'E')
+        .ok_or_else(|| missing("the best signal, E"))?;
+
+    Ok(Heightmap {
+        start,
+        end,
+        heights: squares.map(get_height),
     })
 }
 
-fn path_len(path: Vec<Point>) -> usize {
+pub fn path_len(path: Vec<Point<usize>>) -> usize {
     path.len() - 1
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    println!("{}", env!("CARGO_PKG_NAME"));
-    let args = Cli::parse();
-
-    let filename = &args.file;
-
-    let reader = BufReader::new(File::open(filename)?);
-    let grid = parse_grid(reader)?;
-    //let path = grid.find_shortest_path_a_star().expect("no path found");
-    //let path = grid.find_shortest_path_dijkstra().expect("no path found");
-    //let path = grid.find_shortest_path_bfs().expect("no path found");
-
-    // follow the search
-
-    // for drawing
-    //let (sender, receiver): (Sender<Point>, Receiver<Point>) = mpsc::channel();
-
-    let grid2 = grid.clone();
-    // std::thread::spawn(move || drawing_thread(grid2, receiver).unwrap());
-
-    //let path = grid.find_shortest_path(sender);
-    let path = grid.find_shortest_path_a_star(grid.start /* , sender*/);
-
-    //std::thread::sleep(Duration::from_secs(30));
-
-    println!(
-        "part1, shortest path: {}",
-        path_len(path.expect("no path found"))
-    );
-
-    // find all a points
-    let best_scenic_route = grid
-        .all_of(b'a')
+/// Length of the shortest path from any of the lowest points to the end
+pub fn best_scenic_route(grid: &Heightmap) -> Option<usize> {
+    grid.all_of(b'a')
         .into_iter()
-        .map(|point| grid.find_shortest_path_a_star(point))
-        .filter_map(|path| path)
+        .filter_map(|point| grid.find_shortest_path_a_star(point))
         .map(path_len)
         .min()
-        .expect("no scenic routes found");
-
-    println!("part2, best scenic path: {best_scenic_route}");
-
-    Ok(())
 }
 
-fn drawing_thread(mut grid: Grid, receiver: Receiver<Point>) -> Result<(), Box<dyn Error>> {
+pub fn drawing_thread(
+    mut grid: Heightmap,
+    receiver: Receiver<Point<usize>>,
+) -> Result<(), Box<dyn Error>> {
     let stdout = std::io::stdout();
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     while let Ok(found) = receiver.recv() {
-        grid.data[found.y][found.x] = u8::MAX;
+        grid.heights[found] = u8::MAX;
 
         terminal.draw(|f| {
             let size = f.size();
             let block = Canvas::default()
                 .block(Block::default().title("Canvas").borders(Borders::ALL))
-                .x_bounds([0.0, grid.data[0].len() as f64])
-                .y_bounds([0.0, grid.data.len() as f64])
+                .x_bounds([0.0, grid.heights.width() as f64])
+                .y_bounds([0.0, grid.heights.height() as f64])
                 .paint(|ctx| {
-                    for (point, ch) in grid.data.iter().enumerate().flat_map(|(y, row)| {
-                        row.iter()
-                            .enumerate()
-                            .map(move |(x, ch)| (Point { x, y }, *ch))
-                    }) {
+                    for (point, &ch) in grid.heights.iter() {
                         let color = if found == point {
                             Color::White
                         } else if grid.end == point {
@@ -504,7 +374,8 @@ fn drawing_thread(mut grid: Grid, receiver: Receiver<Point>) -> Result<(), Box<d
                         };
                         ctx.draw(&Rectangle {
                             x: point.x as f64,
-                            y: point.y as f64,
+                            // the canvas's y grows upwards, the grid's down
+                            y: (grid.heights.height() - 1 - point.y) as f64,
                             width: 1.0,
                             height: 1.0,
                             color,
@@ -527,6 +398,24 @@ fn drawing_thread(mut grid: Grid, receiver: Receiver<Point>) -> Result<(), Box<d
     Ok(())
 }
 
+impl Solution for Heightmap {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        parse_grid(input.as_bytes())
+    }
+
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        let path = self
+            .find_shortest_path_a_star(self.start)
+            .ok_or("no path found")?;
+        Ok(path_len(path).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        let steps = best_scenic_route(self).ok_or("no scenic routes found")?;
+        Ok(steps.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -541,16 +430,16 @@ abdefghi
 
     #[test]
     fn test_part1() {
-        let grid = parse_grid(BufReader::new(INPUT.as_bytes())).unwrap();
+        let grid = parse_grid(INPUT.as_bytes()).unwrap();
 
-        let (sender, _receiver): (Sender<Point>, Receiver<Point>) = mpsc::channel();
+        let (sender, _receiver): (Sender<Point<usize>>, Receiver<Point<usize>>) = mpsc::channel();
         assert_eq!(path_len(grid.find_shortest_path(sender)), 31);
     }
 
     #[test]
     fn test_part1_a_star() {
-        let grid = parse_grid(BufReader::new(INPUT.as_bytes())).unwrap();
-        //let (sender, _receiver): (Sender<Point>, Receiver<Point>) = mpsc::channel();
+        let grid = parse_grid(INPUT.as_bytes()).unwrap();
+        //let (sender, _receiver): (Sender<Point<usize>>, Receiver<Point<usize>>) = mpsc::channel();
 
         assert_eq!(
             path_len(
@@ -563,14 +452,14 @@ abdefghi
 
     #[test]
     fn test_part1_dijkstra() {
-        let grid = parse_grid(BufReader::new(INPUT.as_bytes())).unwrap();
+        let grid = parse_grid(INPUT.as_bytes()).unwrap();
 
         assert_eq!(path_len(grid.find_shortest_path_dijkstra().unwrap()), 31);
     }
 
     #[test]
     fn test_part1_bfs() {
-        let grid = parse_grid(BufReader::new(INPUT.as_bytes())).unwrap();
+        let grid = parse_grid(INPUT.as_bytes()).unwrap();
 
         assert_eq!(path_len(grid.find_shortest_path_bfs().unwrap()), 31);
     }
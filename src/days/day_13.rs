@@ -103,6 +103,7 @@
 //! - Compare 6 vs 6
 //! - Compare 7 vs 0
 //! - Right side is smaller, so inputs are not in the right order
+//!
 //! What are the indices of the pairs that are already in the right order? (The first pair has index 1, the second pair has index 2, and so on.) In the above example, the pairs in the right order are 1, 2, 4, and 6; the sum of these indices is 13.
 //!
 //! Determine which pairs of packets are already in the right order. What is the sum of the indices of those pairs?
@@ -143,24 +144,16 @@
 
 use std::cmp::Ordering;
 use std::error::Error;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 
-use clap::Parser;
 use nom::sequence::delimited;
-use nom::{branch::alt, bytes::complete::tag, character, IResult};
-
-/// Cli
-#[derive(Debug, Parser)]
-#[clap(name = "Advent of Code", version, about)]
-struct Cli {
-    /// Disable INFO messages, WARN and ERROR will remain
-    #[clap(short = 'f', long = "file")]
-    pub(crate) file: String,
-}
+use nom::{branch::alt, character};
+
+use crate::parsing::{self, parse_all, parse_lines, tag, IResult, ParseError};
+use crate::solution::{Answer, Solution};
 
 #[derive(Clone, Eq, PartialEq)]
-enum Packet {
+pub enum Packet {
     Literal(usize),
     List(Vec<Packet>),
 }
@@ -207,12 +200,12 @@ impl Ord for Packet {
     }
 }
 
-fn parse_literal(input: &str) -> IResult<&str, Packet> {
-    let (input, literal) = character::complete::u32(input)?;
-    Ok((input, Packet::Literal(literal as usize)))
+pub fn parse_literal(input: &str) -> IResult<'_, Packet> {
+    let (input, literal) = parsing::usize(input)?;
+    Ok((input, Packet::Literal(literal)))
 }
 
-fn parse_set(input: &str) -> IResult<&str, Packet> {
+pub fn parse_set(input: &str) -> IResult<'_, Packet> {
     let (input, packet) = delimited(
         character::complete::char('['),
         nom::multi::separated_list0(tag(","), alt((parse_literal, parse_set))),
@@ -223,52 +216,41 @@ fn parse_set(input: &str) -> IResult<&str, Packet> {
     Ok((input, packet))
 }
 
-fn parse_packet(input: &str) -> Packet {
-    parse_set(input).expect("bad parse").1
+pub fn parse_packet(input: &str) -> Result<Packet, ParseError> {
+    parse_all(input, parse_set)
 }
 
-fn process_packets(reader: impl BufRead) -> Result<usize, Box<dyn Error>> {
-    let mut lines = reader.lines();
-
-    let mut running_total = 0;
-    let mut index = 0;
-    while let Some(line) = lines.next() {
-        let line = line?;
-        if line.is_empty() {
-            continue;
-        }
-
-        index += 1;
-        let packet1 = parse_packet(&line);
-        let packet2 = parse_packet(&lines.next().expect("eof")?);
+/// All of the packets, in the order they were received
+pub fn parse_packets(reader: impl BufRead) -> Result<Vec<Packet>, Box<dyn Error>> {
+    parse_lines(reader, parse_set)
+}
 
-        if !packet1.cmp(&packet2).is_gt() {
-            running_total += index;
-        }
+/// Sum of the indexes of the pairs of packets that are in the right order
+///
+/// An odd number of packets is an error, as the last would have nothing to pair with.
+pub fn process_packets(packets: &[Packet]) -> Result<usize, Box<dyn Error>> {
+    if !packets.len().is_multiple_of(2) {
+        return Err(format!("{} packets can't be split into pairs", packets.len()).into());
     }
 
-    Ok(running_total)
+    Ok(packets
+        .chunks_exact(2)
+        .enumerate()
+        .filter(|(_, pair)| !pair[0].cmp(&pair[1]).is_gt())
+        .map(|(idx, _)| idx + 1)
+        .sum())
 }
 
-fn parse_and_sort_packets(reader: impl BufRead) -> Result<Vec<Packet>, Box<dyn Error>> {
-    let lines = reader.lines();
-
-    let mut packets = lines
-        .into_iter()
-        .map(|line| line.expect("bad data"))
-        .filter(|line| !line.is_empty())
-        .map(|line| parse_packet(&line))
-        .collect::<Vec<Packet>>();
-
+/// Adds the divider packets and sorts them all into the right order
+pub fn sort_packets(mut packets: Vec<Packet>) -> Vec<Packet> {
     packets.push(Packet::List(vec![Packet::List(vec![Packet::Literal(2)])]));
     packets.push(Packet::List(vec![Packet::List(vec![Packet::Literal(6)])]));
 
     packets.sort_unstable();
-
-    Ok(packets)
+    packets
 }
 
-fn product_of_dividers(packets: Vec<Packet>) -> usize {
+pub fn product_of_dividers(packets: Vec<Packet>) -> usize {
     packets
         .iter()
         .enumerate()
@@ -280,24 +262,26 @@ fn product_of_dividers(packets: Vec<Packet>) -> usize {
         .product()
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    println!("{}", env!("CARGO_PKG_NAME"));
-    let args = Cli::parse();
-
-    let filename = &args.file;
-
-    let reader = BufReader::new(File::open(filename)?);
-    let answer = process_packets(reader)?;
-
-    println!("part 1 sum of indexes: {answer}");
+/// The packets of the distress signal, in pairs
+pub struct Packets {
+    pub packets: Vec<Packet>,
+}
 
-    let reader = BufReader::new(File::open(filename)?);
-    let packets = parse_and_sort_packets(reader)?;
+impl Solution for Packets {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            packets: parse_packets(input.as_bytes())?,
+        })
+    }
 
-    let product = product_of_dividers(packets);
-    println!("part 2 product of dividers: {product}");
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(process_packets(&self.packets)?.into())
+    }
 
-    Ok(())
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        let packets = sort_packets(self.packets.clone());
+        Ok(product_of_dividers(packets).into())
+    }
 }
 
 #[cfg(test)]
@@ -357,18 +341,43 @@ mod tests {
 "#;
 
     fn test_process_packets(s: &str) -> usize {
-        process_packets(BufReader::new(s.as_bytes())).unwrap()
+        process_packets(&parse_packets(s.as_bytes()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_parse_packet() {
+        assert_eq!(
+            parse_packet("[1,[2]]").unwrap(),
+            Packet::List(vec![
+                Packet::Literal(1),
+                Packet::List(vec![Packet::Literal(2)])
+            ])
+        );
+        assert_eq!(
+            parse_packet("[1,[2]").unwrap_err(),
+            ParseError::new(7, "']'")
+        );
+        assert_eq!(
+            parse_packet("[1]]").unwrap_err(),
+            ParseError::new(4, "the end of the line")
+        );
     }
 
     #[test]
     fn test_part1() {
-        let sum_of_indexes = process_packets(BufReader::new(INPUT.as_bytes())).unwrap();
-        assert_eq!(sum_of_indexes, 13);
+        let mut packets = parse_packets(INPUT.as_bytes()).unwrap();
+        assert_eq!(process_packets(&packets).unwrap(), 13);
+
+        packets.pop();
+        assert_eq!(
+            process_packets(&packets).unwrap_err().to_string(),
+            "15 packets can't be split into pairs"
+        );
     }
 
     #[test]
     fn test_part2() {
-        let packets = parse_and_sort_packets(BufReader::new(INPUT2.as_bytes())).unwrap();
+        let packets = sort_packets(parse_packets(INPUT2.as_bytes()).unwrap());
 
         assert_eq!(product_of_dividers(packets), 140);
     }
@@ -0,0 +1,506 @@
+//! --- Day 14: Regolith Reservoir ---
+//! The distress signal leads you to a giant waterfall! Actually, hang on - the signal seems like it's coming from the waterfall itself, and that doesn't make any sense. However, you do notice a little path that leads behind the waterfall.
+//!
+//! Correction: the distress signal leads you behind a giant waterfall! There seems to be a large cave system here, and the signal definitely leads further inside.
+//!
+//! As you begin to make your way deeper underground, you feel the ground rumble for a moment. Sand begins pouring into the cave! If you don't quickly figure out where the sand is going, you could quickly become trapped!
+//!
+//! Fortunately, your familiarity with analyzing the path of falling material will come in handy here. You scan a two-dimensional vertical slice of the cave above you (your puzzle input) and discover that it is mostly air with structures made of rock.
+//!
+//! Your scan traces the path of each solid rock structure and reports the x,y coordinates that form the shape of the path, where x represents distance to the right and y represents distance down. Each path appears as a single line of text in your scan. After the first point of each path, each point indicates the end of a straight horizontal or vertical line to be drawn from the previous point. For example:
+//!
+//! 498,4 -> 498,6 -> 496,6
+//! 503,4 -> 502,4 -> 502,9 -> 494,9
+//! This scan means that there are two paths of rock; the first path consists of two straight lines, and the second path consists of three straight lines. (Specifically, the first path consists of a line of rock from 498,4 through 498,6 and another line of rock from 498,6 through 496,6.)
+//!
+//! The sand is pouring into the cave from point 500,0.
+//!
+//! Drawing rock as #, air as ., and the source of the sand as +, this becomes:
+//!
+//!
+//!   4     5  5
+//!   9     0  0
+//!   4     0  3
+//! 0 ......+...
+//! 1 ..........
+//! 2 ..........
+//! 3 ..........
+//! 4 ....#...##
+//! 5 ....#...#.
+//! 6 ..###...#.
+//! 7 ........#.
+//! 8 ........#.
+//! 9 #########.
+//! Sand is produced one unit at a time, and the next unit of sand is not produced until the previous unit of sand comes to rest. A unit of sand is large enough to fill one tile of air in your scan.
+//!
+//! A unit of sand always falls down one step if possible. If the tile immediately below is blocked (by rock or sand), the unit of sand attempts to instead move diagonally one step down and to the left. If that tile is blocked, the unit of sand attempts to instead move diagonally one step down and to the right. Sand keeps moving as long as it is able to do so, at each step trying to move down, then down-left, then down-right. If all three possible destinations are blocked, the unit of sand comes to rest and no longer moves, at which point the next unit of sand is created back at the source.
+//!
+//! So, drawing sand that has come to rest as o, the first unit of sand simply falls straight down and then stops:
+//!
+//! ......+...
+//! ..........
+//! ..........
+//! ..........
+//! ....#...##
+//! ....#...#.
+//! ..###...#.
+//! ........#.
+//! ......o.#.
+//! #########.
+//! The second unit of sand then falls straight down, lands on the first one, and then comes to rest to its left:
+//!
+//! ......+...
+//! ..........
+//! ..........
+//! ..........
+//! ....#...##
+//! ....#...#.
+//! ..###...#.
+//! ........#.
+//! .....oo.#.
+//! #########.
+//! After a total of five units of sand have come to rest, they form this pattern:
+//!
+//! ......+...
+//! ..........
+//! ..........
+//! ..........
+//! ....#...##
+//! ....#...#.
+//! ..###...#.
+//! ......o.#.
+//! ....oooo#.
+//! #########.
+//! After a total of 22 units of sand:
+//!
+//! ......+...
+//! ..........
+//! ......o...
+//! .....ooo..
+//! ....#ooo##
+//! ....#ooo#.
+//! ..###ooo#.
+//! ....oooo#.
+//! ...ooooo#.
+//! #########.
+//! Finally, only two more units of sand can possibly come to rest:
+//!
+//! ......+...
+//! ..........
+//! ......o...
+//! .....ooo..
+//! ....#ooo##
+//! ...o#ooo#.
+//! ..###ooo#.
+//! ....oooo#.
+//! .o.ooooo#.
+//! #########.
+//! Once all 24 units of sand shown above have come to rest, all further sand flows out the bottom, falling into the endless void. Just for fun, the path any new sand takes before falling forever is shown here with ~:
+//!
+//! .......+...
+//! .......~...
+//! ......~o...
+//! .....~ooo..
+//! ....~#ooo##
+//! ...~o#ooo#.
+//! ..~###ooo#.
+//! ..~..oooo#.
+//! .~o.ooooo#.
+//! ~#########.
+//! ~..........
+//! ~..........
+//! ~..........
+//! Using your scan, simulate the falling sand. How many units of sand come to rest before sand starts flowing into the abyss below?
+//!
+//! --- Part Two ---
+//! You realize you misread the scan. There isn't an endless void at the bottom of the scan - there's floor, and you're standing on it!
+//!
+//! You don't have time to scan the floor, so assume the floor is an infinite horizontal line with a y coordinate equal to two plus the highest y coordinate of any point in your scan.
+//!
+//! In the example above, the highest y coordinate of any point is 9, and so the floor is at y=11. (This is as if your scan contained one extra rock path like -infinity,11 -> infinity,11.) With the added floor, the example above now looks like this:
+//!
+//!         ...........+........
+//!         ....................
+//!         ....................
+//!         ....................
+//!         .........#...##.....
+//!         .........#...#......
+//!         .......###...#......
+//!         .............#......
+//!         .............#......
+//!         .....#########......
+//!         ....................
+//! <-- etc #################### etc -->
+//! To find somewhere safe to stand, you'll need to simulate falling sand until a unit of sand comes to rest at 500,0, blocking the source entirely and stopping the flow of sand into the cave. In the example above, the situation finally looks like this after 93 units of sand come to rest:
+//!
+//! ............o............
+//! ...........ooo...........
+//! ..........ooooo..........
+//! .........ooooooo.........
+//! ........oo#ooo##o........
+//! .......ooo#ooo#ooo.......
+//! ......oo###ooo#oooo......
+//! .....oooo.oooo#ooooo.....
+//! ....oooooooooo#oooooo....
+//! ...ooo#########ooooooo...
+//! ..ooooo.......ooooooooo..
+//! #########################
+//! Using your scan, simulate the falling sand until the source of the sand becomes blocked. How many units of sand come to rest?
+
+use std::error::Error;
+use std::fmt;
+use std::io::BufRead;
+
+use nom::character;
+use nom::sequence::tuple;
+
+use crate::geometry::Point;
+use crate::grid::{Grid, SparseGrid};
+use crate::parsing::{self, parse_lines, tag, IResult};
+use crate::solution::{Answer, Solution};
+
+/// Sand pours in from here, y grows downward into the cave
+pub const SAND_START: Point<isize> = Point::new(500, 0);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Next {
+    Free(Point<isize>),
+    Blocked,
+    EndlessVoid,
+}
+
+impl Next {
+    pub fn or_else<F: FnOnce() -> Next>(self, f: F) -> Next {
+        match self {
+            Next::Blocked => f(),
+            next => next,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Rock {
+    pub line: Vec<Point<usize>>,
+}
+
+impl Rock {
+    pub fn new(line: Vec<Point<usize>>) -> Self {
+        Self { line }
+    }
+}
+
+// 498,4
+pub fn parse_point(input: &str) -> IResult<'_, Point<usize>> {
+    let (input, (x, _, y)) = tuple((
+        parsing::usize,
+        character::complete::char(','),
+        parsing::usize,
+    ))(input)?;
+
+    Ok((input, Point::new(x, y)))
+}
+
+// 498,4 -> 498,6 -> 496,6
+pub fn parse_rock(input: &str) -> IResult<'_, Rock> {
+    let (input, points) = nom::multi::separated_list1(tag(" -> "), parse_point)(input)?;
+
+    Ok((input, Rock::new(points)))
+}
+
+pub fn parse_rocks(reader: impl BufRead) -> Result<Vec<Rock>, Box<dyn Error>> {
+    parse_lines(reader, parse_rock)
+}
+
+/// What fills a square of the cave
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Cell {
+    /// How the cell is drawn in the puzzle
+    pub fn symbol(&self) -> char {
+        match self {
+            Cell::Air => '.',
+            Cell::Rock => '#',
+            Cell::Sand => 'o',
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+/// The squares of the cave the sand can come to rest in
+pub enum Ground {
+    /// Without a floor, sand can only rest from the leftmost rock to the rightmost and above the
+    /// deepest, anything outside the grid is the endless void. The grid's left column is at `left`.
+    Bounded { left: isize, grid: Grid<Cell> },
+    /// The floor runs forever either way, so the sand can spread as far as it likes
+    Unbounded(SparseGrid<Cell>),
+}
+
+impl Ground {
+    /// What fills the square, or `None` if it's in the endless void
+    pub fn get(&self, point: Point<isize>) -> Option<Cell> {
+        match self {
+            Ground::Bounded { left, grid } => grid.get(Ground::grid_point(*left, point)?).copied(),
+            Ground::Unbounded(grid) => Some(grid.get(point)),
+        }
+    }
+
+    /// Where the point is in the grid of a bounded cave, if it's right of the grid's left edge
+    /// and below the top
+    fn grid_point(left: isize, point: Point<isize>) -> Option<Point<usize>> {
+        let x = usize::try_from(point.x - left).ok()?;
+        let y = usize::try_from(point.y).ok()?;
+        Some(Point::new(x, y))
+    }
+
+    /// Fill the square, which must not be in the endless void, returning what was there
+    fn fill(&mut self, point: Point<isize>, cell: Cell) -> Cell {
+        match self {
+            Ground::Bounded { left, grid } => {
+                let point = Ground::grid_point(*left, point).expect("a point in the cave");
+                std::mem::replace(&mut grid[point], cell)
+            }
+            Ground::Unbounded(grid) => {
+                let previous = grid.get(point);
+                grid.insert(point, cell);
+                previous
+            }
+        }
+    }
+
+    /// Draw the cave as in the puzzle, all of it when it's bounded or the filled part otherwise
+    pub fn render(&self) -> String {
+        match self {
+            Ground::Bounded { grid, .. } => grid.to_string(),
+            Ground::Unbounded(grid) => grid.render(Cell::symbol),
+        }
+    }
+}
+
+pub struct Cave {
+    pub ground: Ground,
+    pub sand: usize,
+}
+
+/// The depth of the deepest rock, or `None` if there are no rocks
+fn max_depth(rocks: &[Rock]) -> Option<usize> {
+    rocks
+        .iter()
+        .flat_map(|rock| &rock.line)
+        .map(|point| point.y)
+        .max()
+}
+
+/// Every square of rock the lines pass through
+fn rock_points(rocks: &[Rock]) -> impl Iterator<Item = Point<usize>> + '_ {
+    rocks.iter().flat_map(|rock| {
+        rock.line.windows(2).flat_map(|vertices| {
+            let (from, to) = (vertices[0], vertices[1]);
+            (from.y.min(to.y)..=from.y.max(to.y)).flat_map(move |y| {
+                (from.x.min(to.x)..=from.x.max(to.x)).map(move |x| Point::new(x, y))
+            })
+        })
+    })
+}
+
+impl Cave {
+    /// A cave without a floor, or `None` if there are no rocks to bound it
+    pub fn new(rocks: Vec<Rock>) -> Option<Self> {
+        let max_depth = max_depth(&rocks)?;
+        let xs = || {
+            rocks
+                .iter()
+                .flat_map(|rock| &rock.line)
+                .map(|point| point.x)
+        };
+        let left = xs().min()?;
+        let right = xs().max()?;
+
+        let mut grid = Grid::new(right - left + 1, max_depth + 1, Cell::Air);
+        for point in rock_points(&rocks) {
+            grid[Point::new(point.x - left, point.y)] = Cell::Rock;
+        }
+
+        Some(Self::with_ground(Ground::Bounded {
+            left: left as isize,
+            grid,
+        }))
+    }
+
+    /// A cave with a floor `below_max` under the deepest rock, or `None` if there are no rocks
+    pub fn with_floor(rocks: Vec<Rock>, below_max: usize) -> Option<Self> {
+        let floor = (max_depth(&rocks)? + below_max) as isize;
+
+        let mut grid = SparseGrid::with_default(move |point: Point<isize>| {
+            if point.y == floor {
+                Cell::Rock
+            } else {
+                Cell::Air
+            }
+        });
+        for point in rock_points(&rocks) {
+            grid.insert(point.signed(), Cell::Rock);
+        }
+
+        Some(Self::with_ground(Ground::Unbounded(grid)))
+    }
+
+    fn with_ground(ground: Ground) -> Self {
+        Self { ground, sand: 0 }
+    }
+
+    fn can_drop_to(&self, from: &Point<isize>, dx: isize) -> Next {
+        let to = from.offset(dx, 1);
+        match self.ground.get(to) {
+            None => Next::EndlessVoid,
+            Some(Cell::Air) => Next::Free(to),
+            Some(_) => Next::Blocked,
+        }
+    }
+
+    fn can_drop(&self, from: &Point<isize>) -> Next {
+        self.can_drop_to(from, 0)
+            .or_else(|| self.can_drop_to(from, -1))
+            .or_else(|| self.can_drop_to(from, 1))
+    }
+
+    pub fn drop_sand(&mut self) {
+        loop {
+            let mut point = SAND_START;
+
+            loop {
+                match self.can_drop(&point) {
+                    Next::Free(next) => point = next,
+                    Next::Blocked => break,
+                    Next::EndlessVoid => return,
+                }
+            }
+
+            let previous = self.ground.fill(point, Cell::Sand);
+            assert_eq!(previous, Cell::Air, "point already filled: {point:?}");
+            self.sand += 1;
+
+            if point == SAND_START {
+                return;
+            }
+        }
+    }
+
+    pub fn sand_count(&self) -> usize {
+        self.sand
+    }
+}
+
+const NO_ROCKS: &str = "the scan has no rocks";
+
+pub struct Scan {
+    pub rocks: Vec<Rock>,
+}
+
+impl Solution for Scan {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            rocks: parse_rocks(input.as_bytes())?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        let mut cave = Cave::new(self.rocks.clone()).ok_or(NO_ROCKS)?;
+        cave.drop_sand();
+        Ok(cave.sand_count().into())
+    }
+
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        let mut cave = Cave::with_floor(self.rocks.clone(), 2).ok_or(NO_ROCKS)?;
+        cave.drop_sand();
+        Ok(cave.sand_count().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+"#;
+
+    #[test]
+    fn test_parse() {
+        let lines = parse_rocks(INPUT.as_bytes()).unwrap();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].line.len(), 3);
+        assert_eq!(lines[1].line.len(), 4);
+    }
+
+    #[test]
+    fn test_part1() {
+        let rocks = parse_rocks(INPUT.as_bytes()).unwrap();
+        let mut cave = Cave::new(rocks).unwrap();
+
+        cave.drop_sand();
+        assert_eq!(cave.sand_count(), 24);
+        assert_eq!(
+            cave.ground.render(),
+            "\
+..........
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.
+"
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let rocks = parse_rocks(INPUT.as_bytes()).unwrap();
+        let mut cave = Cave::with_floor(rocks, 2).unwrap();
+
+        cave.drop_sand();
+        assert_eq!(cave.sand_count(), 93);
+    }
+
+    #[test]
+    fn test_no_rocks() {
+        let scan = Scan::parse("").unwrap();
+        assert_eq!(scan.part1().unwrap_err().to_string(), NO_ROCKS);
+        assert_eq!(scan.part2().unwrap_err().to_string(), NO_ROCKS);
+
+        // a rock that's a single point still bounds the cave
+        let rocks = parse_rocks("500,2".as_bytes()).unwrap();
+        let mut cave = Cave::new(rocks).unwrap();
+        cave.drop_sand();
+        assert_eq!(cave.sand_count(), 0);
+    }
+
+    #[test]
+    fn test_floor_is_endless() {
+        let rocks = parse_rocks(INPUT.as_bytes()).unwrap();
+
+        // the floor carries on past x = 0, where the bounded cave has long ended
+        let cave = Cave::with_floor(rocks.clone(), 2).unwrap();
+        assert_eq!(cave.ground.get(Point::new(-1000, 11)), Some(Cell::Rock));
+        assert_eq!(cave.ground.get(Point::new(-1000, 10)), Some(Cell::Air));
+
+        let cave = Cave::new(rocks).unwrap();
+        assert_eq!(cave.ground.get(Point::new(-1000, 9)), None);
+        assert_eq!(cave.ground.get(Point::new(494, 9)), Some(Cell::Rock));
+        assert_eq!(cave.ground.get(Point::new(494, -1)), None);
+    }
+}
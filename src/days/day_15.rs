@@ -104,72 +104,47 @@
 //! Find the only possible position for the distress beacon. What is its tuning frequency?
 
 use std::error::Error;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufRead;
 use std::ops::Range;
 
-use clap::Parser;
 use nom::sequence::preceded;
-use nom::{bytes::complete::tag, character, IResult};
 use rayon::prelude::*;
 
-/// Cli
-#[derive(Debug, Parser)]
-#[clap(name = "Advent of Code", version, about)]
-struct Cli {
-    /// Disable INFO messages, WARN and ERROR will remain
-    #[clap(short = 'f', long = "file")]
-    pub(crate) file: String,
-}
-
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-struct Point {
-    x: isize,
-    y: isize,
-}
-
-impl Point {
-    fn distance(&self, other: &Self) -> usize {
-        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
-    }
-}
+use crate::geometry::Point;
+use crate::intervals::IntervalSet;
+use crate::parsing::{self, parse_lines, tag, IResult};
+use crate::solution::{Answer, Solution};
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-struct Sensor {
-    location: Point,
-    closest_beacon: Beacon,
+pub struct Sensor {
+    pub location: Point,
+    pub closest_beacon: Beacon,
 }
 
 impl Sensor {
-    fn range(&self) -> usize {
-        self.location.distance(&self.closest_beacon.0)
+    pub fn range(&self) -> usize {
+        self.location.manhattan_distance(&self.closest_beacon.0)
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-struct Beacon(Point);
+pub struct Beacon(pub Point);
 
 impl Beacon {
-    fn frequency(&self) -> usize {
+    pub fn frequency(&self) -> usize {
         (self.0.x as usize * 4000000) + (self.0.y as usize)
     }
 }
 
-fn parse_point(input: &str) -> IResult<&str, Point> {
-    let (input, x) = preceded(tag("x="), character::complete::i32)(input)?;
+pub fn parse_point(input: &str) -> IResult<'_, Point> {
+    let (input, x) = preceded(tag("x="), parsing::isize)(input)?;
     let (input, _) = tag(", ")(input)?;
-    let (input, y) = preceded(tag("y="), character::complete::i32)(input)?;
+    let (input, y) = preceded(tag("y="), parsing::isize)(input)?;
 
-    Ok((
-        input,
-        Point {
-            x: x as isize,
-            y: y as isize,
-        },
-    ))
+    Ok((input, Point::new(x, y)))
 }
 
-fn parse_sensor_and_beacon(input: &str) -> IResult<&str, Sensor> {
+pub fn parse_sensor_and_beacon(input: &str) -> IResult<'_, Sensor> {
     let (input, _) = tag("Sensor at ")(input)?;
     let (input, sensor) = parse_point(input)?;
 
@@ -184,191 +159,98 @@ fn parse_sensor_and_beacon(input: &str) -> IResult<&str, Sensor> {
     ))
 }
 
-fn parse_sensors(reader: impl BufRead) -> Vec<Sensor> {
-    reader
-        .lines()
-        .filter_map(|s| s.ok())
-        .filter(|s| !s.is_empty())
-        .map(|s| parse_sensor_and_beacon(&s).expect("bad data").1)
-        .collect()
+pub fn parse_sensors(reader: impl BufRead) -> Result<Vec<Sensor>, Box<dyn Error>> {
+    parse_lines(reader, parse_sensor_and_beacon)
 }
 
-fn get_beacons(sensors: &[Sensor]) -> Vec<Beacon> {
+pub fn get_beacons(sensors: &[Sensor]) -> Vec<Beacon> {
     sensors
         .iter()
         .map(|sensor| sensor.closest_beacon.clone())
         .collect::<Vec<_>>()
 }
 
-fn is_excluded(sensors: &[Sensor], beacons: &[Beacon], point: &Point) -> bool {
-    if beacons.contains(&Beacon(point.clone())) {
-        return false;
-    }
-
-    sensors
-        .iter()
-        .filter(|sensor| sensor.closest_beacon.0 != *point)
-        .any(|sensor| sensor.location.distance(&point) <= sensor.range())
-}
-
-fn is_excluded2(sensors: &[Sensor], point: &Point) -> Option<Sensor> {
+/// The x coordinates the sensors can see along the row
+pub fn row_coverage(sensors: &[Sensor], y: isize) -> IntervalSet<isize> {
     sensors
         .iter()
-        .filter(|sensor| sensor.closest_beacon.0 != *point)
-        .find(|sensor| sensor.location.distance(&point) <= sensor.range())
-        .cloned()
+        .filter_map(|sensor| {
+            let reach = sensor.range().checked_sub(sensor.location.y.abs_diff(y))? as isize;
+            Some(sensor.location.x - reach..=sensor.location.x + reach)
+        })
+        .collect()
 }
 
-fn count_spaces_in_range(sensors: &[Sensor], beacons: &[Beacon], y: isize) -> usize {
-    // get min and max of x to get grid size...
-    let x_min = sensors
-        .iter()
-        .flat_map(|sensor| {
-            [
-                sensor.location.x - sensor.range() as isize,
-                sensor.closest_beacon.0.x,
-            ]
-        })
-        .min()
-        .expect("no x coords");
-    let x_max = sensors
+pub fn count_spaces_in_range(sensors: &[Sensor], beacons: &[Beacon], y: isize) -> usize {
+    let beacons_on_row = beacons
         .iter()
-        .flat_map(|sensor| {
-            [
-                sensor.location.x + sensor.range() as isize,
-                sensor.closest_beacon.0.x,
-            ]
-        })
-        .max()
-        .expect("no x coords");
-
-    let mut excluded_spaces_count = 0;
-    for x in x_min..=x_max {
-        let point = Point { x, y };
-        if is_excluded(sensors, beacons, &point) {
-            excluded_spaces_count += 1;
-        };
-    }
+        .filter(|beacon| beacon.0.y == y)
+        .map(|beacon| beacon.0.x..=beacon.0.x)
+        .collect();
 
-    excluded_spaces_count
+    row_coverage(sensors, y).difference(&beacons_on_row).len()
 }
 
-fn locate_distress_beacon2(
+/// Find the only point in the square the sensors can't see, looking for a gap along each row
+///
+/// If no point is unseen, or more than one is, there's no telling which is the beacon.
+pub fn locate_distress_beacon_by_rows(
     sensors: &[Sensor],
-    beacons: &[Beacon],
     x_and_y_range: Range<isize>,
 ) -> Option<Beacon> {
+    if x_and_y_range.is_empty() {
+        return None;
+    }
+
+    let row = IntervalSet::from(x_and_y_range.start..=x_and_y_range.end - 1);
+
+    // each row gives its one unseen point if it has one, and a second point anywhere is an
+    // error, which stops the search
     x_and_y_range
-        .clone()
         .into_par_iter()
-        .find_map_first(|y| {
-            if y as usize % 1000 == 0 {
-                dbg!(y);
-            }
-            let mut x = x_and_y_range.clone().min().expect("no min");
-
-            while x < x_and_y_range.clone().max().expect("no max") {
-                let point = Point { x, y };
-                if let Some(sensor) = is_excluded2(sensors, &point) {
-                    if sensor.location.x > x {
-                        // skip to the furthest stop on x
-                        x += sensor.range() as isize - sensor.location.y.abs_diff(y) as isize;
-                    } else {
-                        x += 1;
-                    }
-                } else {
-                    if !beacons.contains(&Beacon(point.clone())) {
-                        return Some(point);
-                    } else {
-                        x += 1;
-                    }
-                }
+        .map(|y| {
+            let gap = row.difference(&row_coverage(sensors, y));
+            match gap.len() {
+                0 => Ok(None),
+                1 => Ok(gap.span().map(|x| Point::new(*x.start(), y))),
+                _ => Err(()),
             }
-            None
-
-            // for x in x_and_y_range
-            // .clone()
-
-            // x_and_y_range
-            //     .clone()
-            //     .into_iter()
-            //     .map(|x| Point { x, y })
-            //     .find(|point| {
-            //         !beacons.contains(&Beacon((point).clone()))
-            //             && !is_excluded(sensors, beacons, point)
-            //     })
         })
-        .map(|point| Beacon(point))
-
-    // x_and_y_range
-    //     .clone()
-    //     .flat_map(|x| x_and_y_range.clone().map(move |y| Point { x, y }))
-    //     .into_iter()
-    //     .find(|point| {
-    //         !beacons.contains(&Beacon((*point).clone())) && !is_excluded(sensors, beacons, point)
-    //     })
-    //     .map(|point| Beacon(point.clone()))
+        .try_reduce(
+            || None,
+            |a, b| match (a, b) {
+                (Some(_), Some(_)) => Err(()),
+                (a, b) => Ok(a.or(b)),
+            },
+        )
+        .ok()
+        .flatten()
+        .map(Beacon)
 }
 
-#[allow(unused)]
-fn locate_distress_beacon(
-    sensors: &[Sensor],
-    beacons: &[Beacon],
-    x_and_y_range: Range<isize>,
-) -> Option<Beacon> {
-    x_and_y_range
-        .clone()
-        .into_par_iter()
-        .find_map_first(|y| {
-            x_and_y_range
-                .clone()
-                .into_iter()
-                .map(|x| Point { x, y })
-                .find(|point| {
-                    !beacons.contains(&Beacon((point).clone()))
-                        && !is_excluded(sensors, beacons, point)
-                })
-        })
-        .map(|point| Beacon(point))
-
-    // x_and_y_range
-    //     .clone()
-    //     .flat_map(|x| x_and_y_range.clone().map(move |y| Point { x, y }))
-    //     .into_iter()
-    //     .find(|point| {
-    //         !beacons.contains(&Beacon((*point).clone())) && !is_excluded(sensors, beacons, point)
-    //     })
-    //     .map(|point| Beacon(point.clone()))
+/// The sensors, each with the closest beacon it found
+pub struct Sensors {
+    pub sensors: Vec<Sensor>,
+    pub beacons: Vec<Beacon>,
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    println!("{}", env!("CARGO_PKG_NAME"));
-    let args = Cli::parse();
-
-    let filename = &args.file;
-
-    let reader = BufReader::new(File::open(filename)?);
-    let sensors = parse_sensors(reader);
+impl Solution for Sensors {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        let sensors = parse_sensors(input.as_bytes())?;
+        let beacons = get_beacons(&sensors);
 
-    for sensor in &sensors {
-        println!("Sensor {:?} has range {}", sensor.location, sensor.range());
+        Ok(Self { sensors, beacons })
     }
 
-    let beacons = get_beacons(&sensors);
-    let empty_spaces = count_spaces_in_range(&sensors, &beacons, 2000000);
-
-    println!("part1, spaces without beacon: {empty_spaces}");
-
-    let reader = BufReader::new(File::open(filename)?);
-    let sensors = parse_sensors(reader);
-    let beacons = get_beacons(&sensors);
-    //let beacon = locate_distress_beacon(&sensors, &beacons, 0..4000000).expect("no beacon");
-    let beacon = locate_distress_beacon2(&sensors, &beacons, 0..4000000).expect("no beacon");
-    let frequency = beacon.frequency();
-
-    println!("part2, distress beacon frequency: {frequency}");
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(count_spaces_in_range(&self.sensors, &self.beacons, 2000000).into())
+    }
 
-    Ok(())
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        let beacon =
+            locate_distress_beacon_by_rows(&self.sensors, 0..4000000).ok_or("no beacon")?;
+        Ok(beacon.frequency().into())
+    }
 }
 
 #[cfg(test)]
@@ -394,7 +276,7 @@ Sensor at x=20, y=1: closest beacon is at x=15, y=3
 
     #[test]
     fn test_parse() {
-        parse_sensors(BufReader::new(INPUT.as_bytes()));
+        parse_sensors(INPUT.as_bytes()).unwrap();
     }
 
     #[test]
@@ -411,26 +293,31 @@ Sensor at x=20, y=1: closest beacon is at x=15, y=3
 
     #[test]
     fn test_part1() {
-        let sensors = parse_sensors(BufReader::new(INPUT.as_bytes()));
+        let sensors = parse_sensors(INPUT.as_bytes()).unwrap();
         let beacons = get_beacons(&sensors);
         assert_eq!(count_spaces_in_range(&sensors, &beacons, 10), 26);
     }
 
     #[test]
     fn test_part2() {
-        let sensors = parse_sensors(BufReader::new(INPUT.as_bytes()));
-        let beacons = get_beacons(&sensors);
-        let beacon = locate_distress_beacon(&sensors, &beacons, 0..20).expect("no beacon");
+        let sensors = parse_sensors(INPUT.as_bytes()).unwrap();
+        assert_eq!(row_coverage(&sensors, 11).gaps().len(), 1);
+
+        let beacon = locate_distress_beacon_by_rows(&sensors, 0..21).expect("no beacon");
         assert_eq!(beacon.0, Point { x: 14, y: 11 });
         assert_eq!(beacon.frequency(), 56000011);
     }
 
     #[test]
-    fn test_part2_optimized() {
-        let sensors = parse_sensors(BufReader::new(INPUT.as_bytes()));
-        let beacons = get_beacons(&sensors);
-        let beacon = locate_distress_beacon2(&sensors, &beacons, 0..20).expect("no beacon");
-        assert_eq!(beacon.0, Point { x: 14, y: 11 });
-        assert_eq!(beacon.frequency(), 56000011);
+    fn test_part2_not_one_point() {
+        let sensors = parse_sensors(INPUT.as_bytes()).unwrap();
+
+        // a bigger square has unseen points in its corners as well as the gap
+        assert_eq!(locate_distress_beacon_by_rows(&sensors, -10..30), None);
+        assert_eq!(locate_distress_beacon_by_rows(&sensors, 0..0), None);
+        assert_eq!(locate_distress_beacon_by_rows(&[], 0..20), None);
+
+        let sensors = Sensors::parse("").unwrap();
+        assert!(sensors.part2().is_err());
     }
 }
@@ -0,0 +1,646 @@
+//! --- Day 2: Rock Paper Scissors ---
+//! The Elves begin to set up camp on the beach. To decide whose tent gets to be closest to the snack storage, a giant Rock Paper Scissors tournament is already in progress.
+//!
+//! Rock Paper Scissors is a game between two players. Each game contains many rounds; in each round, the players each simultaneously choose one of Rock, Paper, or Scissors using a hand shape. Then, a winner for that round is selected: Rock defeats Scissors, Scissors defeats Paper, and Paper defeats Rock. If both players choose the same shape, the round instead ends in a draw.
+//!
+//! Appreciative of your help yesterday, one Elf gives you an encrypted strategy guide (your puzzle input) that they say will be sure to help you win. "The first column is what your opponent is going to play: A for Rock, B for Paper, and C for Scissors. The second column--" Suddenly, the Elf is called away to help with someone's tent.
+//!
+//! The second column, you reason, must be what you should play in response: X for Rock, Y for Paper, and Z for Scissors. Winning every time would be suspicious, so the responses must have been carefully chosen.
+//!
+//! The winner of the whole tournament is the player with the highest score. Your total score is the sum of your scores for each round. The score for a single round is the score for the shape you selected (1 for Rock, 2 for Paper, and 3 for Scissors) plus the score for the outcome of the round (0 if you lost, 3 if the round was a draw, and 6 if you won).
+//!
+//! Since you can't be sure if the Elf is trying to help you or trick you, you should calculate the score you would get if you were to follow the strategy guide.
+//!
+//! For example, suppose you were given the following strategy guide:
+//!
+//! A Y
+//! B X
+//! C Z
+//! This strategy guide predicts and recommends the following:
+//!
+//! In the first round, your opponent will choose Rock (A), and you should choose Paper (Y). This ends in a win for you with a score of 8 (2 because you chose Paper + 6 because you won).
+//! In the second round, your opponent will choose Paper (B), and you should choose Rock (X). This ends in a loss for you with a score of 1 (1 + 0).
+//! The third round is a draw with both players choosing Scissors, giving you a score of 3 + 3 = 6.
+//! In this example, if you were to follow the strategy guide, you would get a total score of 15 (8 + 1 + 6).
+//!
+//! What would your total score be if everything goes exactly according to your strategy guide?
+//!
+//! --- Part Two ---
+//! The Elf finishes helping with the tent and sneaks back over to you. "Anyway, the second column says how the round needs to end: X means you need to lose, Y means you need to end the round in a draw, and Z means you need to win. Good luck!"
+//!
+//! The total score is still calculated in the same way, but now you need to figure out what shape to choose so the round ends as indicated. The example above now goes like this:
+//!
+//! In the first round, your opponent will choose Rock (A), and you need the round to end in a draw (Y), so you also choose Rock. This gives you a score of 1 + 3 = 4.
+//! In the second round, your opponent will choose Paper (B), and you choose Rock so you lose (X) with a score of 1 + 0 = 1.
+//! In the third round, you will defeat your opponent's Scissors with Rock for a score of 1 + 6 = 7.
+//! Now that you're correctly decrypting the ultra top secret strategy guide, you would get a total score of 12.
+//!
+//! Following the Elf's instructions for the second column, what would your total score be if everything goes exactly according to your strategy guide?
+//!
+
+use std::error::Error;
+use std::fmt;
+use std::sync::LazyLock;
+
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+
+const WIN: usize = 6;
+const DRAW: usize = 3;
+const LOSS: usize = 0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum End {
+    Win,
+    Lose,
+    Draw,
+}
+
+impl fmt::Display for End {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let end = match self {
+            End::Win => "win",
+            End::Lose => "loss",
+            End::Draw => "draw",
+        };
+        f.pad(end)
+    }
+}
+
+/// A shape, by its place in the cycle of its [`Ruleset`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shape(pub usize);
+
+/// The shapes of the default ruleset, worth the points they're numbered with
+#[derive(Debug, Clone, Copy)]
+#[repr(usize)]
+pub enum Rochambeau {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+impl From<Rochambeau> for Shape {
+    fn from(shape: Rochambeau) -> Self {
+        Shape(shape as usize - 1)
+    }
+}
+
+impl Rochambeau {
+    const ALL: [Rochambeau; 3] = [Rochambeau::Rock, Rochambeau::Paper, Rochambeau::Scissors];
+
+    pub fn play(self, opponent: Rochambeau) -> usize {
+        DEFAULT_RULES.outcome_points(DEFAULT_RULES.outcome(self.into(), opponent.into()))
+    }
+
+    pub fn choose_for_end(self, end: End) -> Self {
+        let Shape(shape) = DEFAULT_RULES.choose_for_end(self.into(), end);
+        Self::ALL[shape]
+    }
+}
+
+/// The shapes of a game, what the letters of a strategy guide mean, and how rounds are scored
+///
+/// The shapes form a cycle where each beats the shapes an odd number of places before it,
+/// wrapping around, so with an odd number of shapes each beats exactly half of the others. Rock,
+/// Paper, Scissors is the smallest, and Rock, Paper, Scissors, Spock, Lizard the next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ruleset {
+    /// Each shape's name and the points for playing it, in the order of the cycle
+    shapes: Vec<(String, usize)>,
+    /// The first column's letters for each shape, in the same order
+    opponent_letters: Vec<String>,
+    /// The second column's letters, when it's the shape to play
+    player_letters: Vec<String>,
+    /// The second column's letters for a loss, draw and win, when it's how the round should end
+    end_letters: [String; 3],
+    win: usize,
+    draw: usize,
+    loss: usize,
+}
+
+/// The default ruleset, built once for the [`Rochambeau`] shapes to share
+static DEFAULT_RULES: LazyLock<Ruleset> = LazyLock::new(Ruleset::default);
+
+impl Default for Ruleset {
+    /// Rock, Paper and Scissors as the puzzle plays them
+    fn default() -> Self {
+        Self::new(&[("Rock", 1), ("Paper", 2), ("Scissors", 3)]).expect("three shapes are valid")
+    }
+}
+
+impl Ruleset {
+    /// A ruleset of the shapes, each with the points for playing it, in the order of the cycle
+    ///
+    /// There must be an odd number of shapes, from 3 to 13, so that every pair has a winner and
+    /// there are letters for them. The opponent's letters start from A and the player's end at
+    /// Z, and the ends are X, Y and Z, as in the puzzle. Each can be changed afterwards.
+    pub fn new(shapes: &[(&str, usize)]) -> Result<Self, String> {
+        let count = shapes.len();
+        if !(3..=13).contains(&count) || count.is_multiple_of(2) {
+            return Err(format!(
+                "a ruleset needs an odd number of shapes from 3 to 13, not {count}"
+            ));
+        }
+
+        let letters = |first: u8| -> Vec<String> {
+            (first..first + count as u8)
+                .map(|letter| char::from(letter).to_string())
+                .collect()
+        };
+
+        Ok(Self {
+            shapes: shapes
+                .iter()
+                .map(|(name, points)| (name.to_string(), *points))
+                .collect(),
+            opponent_letters: letters(b'A'),
+            player_letters: letters(b'Z' + 1 - count as u8),
+            end_letters: ["X", "Y", "Z"].map(String::from),
+            win: WIN,
+            draw: DRAW,
+            loss: LOSS,
+        })
+    }
+
+    /// Rock, Paper, Scissors, Spock and Lizard, played for 1 to 5 points
+    pub fn rock_paper_scissors_spock_lizard() -> Self {
+        let shapes = [
+            ("Rock", 1),
+            ("Paper", 2),
+            ("Scissors", 3),
+            ("Spock", 4),
+            ("Lizard", 5),
+        ];
+        Self::new(&shapes).expect("five shapes are valid")
+    }
+
+    /// Use these letters in the first column, one for each shape
+    pub fn with_opponent_letters(mut self, letters: &[&str]) -> Result<Self, String> {
+        self.opponent_letters = self.shape_letters(letters)?;
+        Ok(self)
+    }
+
+    /// Use these letters in the second column when it's the shape to play, one for each shape
+    pub fn with_player_letters(mut self, letters: &[&str]) -> Result<Self, String> {
+        self.player_letters = self.shape_letters(letters)?;
+        Ok(self)
+    }
+
+    fn shape_letters(&self, letters: &[&str]) -> Result<Vec<String>, String> {
+        if letters.len() != self.shapes.len() {
+            return Err(format!(
+                "there are {} shapes, but {} letters",
+                self.shapes.len(),
+                letters.len()
+            ));
+        }
+
+        check_letters(letters)?;
+        Ok(letters.iter().map(|letter| letter.to_string()).collect())
+    }
+
+    /// Use these letters in the second column when it's how the round should end
+    pub fn with_end_letters(mut self, lose: &str, draw: &str, win: &str) -> Result<Self, String> {
+        check_letters(&[lose, draw, win])?;
+        self.end_letters = [lose, draw, win].map(String::from);
+        Ok(self)
+    }
+
+    /// Score these points for winning, drawing and losing a round
+    pub fn with_points(mut self, win: usize, draw: usize, loss: usize) -> Self {
+        (self.win, self.draw, self.loss) = (win, draw, loss);
+        self
+    }
+
+    pub fn shape_count(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn name(&self, Shape(shape): Shape) -> &str {
+        &self.shapes[shape].0
+    }
+
+    pub fn points(&self, Shape(shape): Shape) -> usize {
+        self.shapes[shape].1
+    }
+
+    /// How the round ends for the player
+    pub fn outcome(&self, Shape(player): Shape, Shape(opponent): Shape) -> End {
+        let count = self.shape_count();
+        match (player + count - opponent) % count {
+            0 => End::Draw,
+            places if places % 2 == 1 => End::Win,
+            _ => End::Lose,
+        }
+    }
+
+    pub fn outcome_points(&self, end: End) -> usize {
+        match end {
+            End::Win => self.win,
+            End::Draw => self.draw,
+            End::Lose => self.loss,
+        }
+    }
+
+    /// The shape that ends the round as wanted, the next in the cycle beats the opponent's and
+    /// the one before loses to it
+    pub fn choose_for_end(&self, Shape(opponent): Shape, end: End) -> Shape {
+        let count = self.shape_count();
+        match end {
+            End::Draw => Shape(opponent),
+            End::Win => Shape((opponent + 1) % count),
+            End::Lose => Shape((opponent + count - 1) % count),
+        }
+    }
+
+    pub fn score_round(&self, opponent: Shape, player: Shape) -> usize {
+        self.outcome_points(self.outcome(player, opponent)) + self.points(player)
+    }
+
+    pub fn score_round_for_end(&self, opponent: Shape, end: End) -> usize {
+        self.score_round(opponent, self.choose_for_end(opponent, end))
+    }
+
+    /// The shape scoring the most against the opponent's, the earliest if several do
+    pub fn best_play(&self, opponent: Shape) -> Shape {
+        (0..self.shape_count())
+            .map(Shape)
+            .rev()
+            .max_by_key(|&player| self.score_round(opponent, player))
+            .expect("a ruleset has shapes")
+    }
+
+    /// The outcome and score of playing the shape
+    pub fn play(&self, opponent: Shape, player: Shape) -> Play {
+        Play {
+            shape: player,
+            outcome: self.outcome(player, opponent),
+            score: self.score_round(opponent, player),
+        }
+    }
+
+    // A Y
+    pub fn parse_round(&self, line: &str) -> Result<Round, ParseError> {
+        let (first, second) = line
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(line.len() + 1, "a space between the columns"))?;
+
+        let opponent = position(&self.opponent_letters, first)
+            .map(Shape)
+            .ok_or_else(|| {
+                ParseError::new(1, format!("a shape of {}", one_of(&self.opponent_letters)))
+            })?;
+
+        let player = position(&self.player_letters, second).map(Shape);
+        let end =
+            position(&self.end_letters, second).map(|end| [End::Lose, End::Draw, End::Win][end]);
+
+        if player.is_none() && end.is_none() {
+            let mut letters = self.player_letters.clone();
+            for letter in &self.end_letters {
+                if !letters.contains(letter) {
+                    letters.push(letter.clone());
+                }
+            }
+
+            let expected = format!("a shape or end of {}", one_of(&letters));
+            return Err(ParseError::new(first.len() + 2, expected));
+        }
+
+        Ok(Round {
+            opponent,
+            player,
+            end,
+        })
+    }
+}
+
+/// Check the letters of a column can be told apart, each one a word and none repeated
+fn check_letters(letters: &[&str]) -> Result<(), String> {
+    for (i, letter) in letters.iter().enumerate() {
+        if letter.is_empty() || letter.contains(char::is_whitespace) {
+            return Err(format!("{letter:?} can't be read as a letter of the guide"));
+        }
+        if letters[..i].contains(letter) {
+            return Err(format!("{letter} stands for more than one thing"));
+        }
+    }
+
+    Ok(())
+}
+
+fn position(letters: &[String], letter: &str) -> Option<usize> {
+    letters.iter().position(|l| l == letter)
+}
+
+/// The letters as a list, like "A, B or C"
+fn one_of(letters: &[String]) -> String {
+    match letters {
+        [] => String::new(),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} or {last}", rest.join(", ")),
+    }
+}
+
+pub fn score_round(opponent: Rochambeau, player: Rochambeau) -> usize {
+    DEFAULT_RULES.score_round(opponent.into(), player.into())
+}
+
+pub fn score_round_for_end(opponent: Rochambeau, end: End) -> usize {
+    DEFAULT_RULES.score_round_for_end(opponent.into(), end)
+}
+
+/// A line of the strategy guide
+///
+/// The second column is read both ways, as a shape to play and as how the round should end. A
+/// ruleset can give a letter only one of those meanings, so either may be missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: Shape,
+    pub player: Option<Shape>,
+    pub end: Option<End>,
+}
+
+/// A shape played in a round, with how the round ended and what it scored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub shape: Shape,
+    pub outcome: End,
+    pub score: usize,
+}
+
+/// A round played each way, with the running totals
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundReport {
+    pub opponent: Shape,
+    /// Playing the second column as the shape, None if it isn't one
+    pub as_shape: Option<(Play, usize)>,
+    /// Playing for the second column as the end, None if it isn't one
+    pub as_end: Option<(Play, usize)>,
+    /// Playing the best shape against the opponent's, whatever the guide says
+    pub best: (Play, usize),
+}
+
+pub struct StrategyGuide {
+    pub rules: Ruleset,
+    pub rounds: Vec<Round>,
+}
+
+impl StrategyGuide {
+    /// Parse the guide with the letters of the ruleset
+    pub fn parse_with(input: &str, rules: Ruleset) -> Result<Self, ParseError> {
+        let mut rounds = Vec::new();
+
+        for (number, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            rounds.push(rules.parse_round(line).map_err(|e| e.on_line(number + 1))?);
+        }
+
+        Ok(Self { rules, rounds })
+    }
+
+    /// Total score when the second column is the shape to play
+    pub fn score(&self) -> Result<usize, String> {
+        self.rounds
+            .iter()
+            .enumerate()
+            .try_fold(0, |total, (i, round)| {
+                let player = round
+                    .player
+                    .ok_or_else(|| format!("round {} has no shape to play", i + 1))?;
+                Ok(total + self.rules.score_round(round.opponent, player))
+            })
+    }
+
+    /// Total score when the second column is how the round needs to end
+    pub fn score_for_end(&self) -> Result<usize, String> {
+        self.rounds
+            .iter()
+            .enumerate()
+            .try_fold(0, |total, (i, round)| {
+                let end = round
+                    .end
+                    .ok_or_else(|| format!("round {} has no end to play for", i + 1))?;
+                Ok(total + self.rules.score_round_for_end(round.opponent, end))
+            })
+    }
+
+    /// The most that could be scored knowing only the opponent's shapes
+    pub fn best_score(&self) -> usize {
+        self.rounds
+            .iter()
+            .map(|round| {
+                let player = self.rules.best_play(round.opponent);
+                self.rules.score_round(round.opponent, player)
+            })
+            .sum()
+    }
+
+    /// Each round played by both readings of the guide and by the best shape, with the running
+    /// total of each
+    ///
+    /// The totals of a reading stop at its first round that the second column has no meaning for.
+    pub fn report(&self) -> Vec<RoundReport> {
+        let mut totals = (Some(0), Some(0), 0);
+
+        self.rounds
+            .iter()
+            .map(|round| {
+                let as_shape = round
+                    .player
+                    .map(|shape| self.rules.play(round.opponent, shape));
+                let as_end = round.end.map(|end| {
+                    let shape = self.rules.choose_for_end(round.opponent, end);
+                    self.rules.play(round.opponent, shape)
+                });
+                let best = self
+                    .rules
+                    .play(round.opponent, self.rules.best_play(round.opponent));
+
+                totals.0 = totals
+                    .0
+                    .zip(as_shape)
+                    .map(|(total, play)| total + play.score);
+                totals.1 = totals.1.zip(as_end).map(|(total, play)| total + play.score);
+                totals.2 += best.score;
+
+                RoundReport {
+                    opponent: round.opponent,
+                    as_shape: as_shape.zip(totals.0),
+                    as_end: as_end.zip(totals.1),
+                    best: (best, totals.2),
+                }
+            })
+            .collect()
+    }
+}
+
+impl Solution for StrategyGuide {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse_with(input, Ruleset::default())?)
+    }
+
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.score()?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.score_for_end()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_data_part_1() {
+        assert_eq!(score_round(Rochambeau::Rock, Rochambeau::Paper), 8);
+        assert_eq!(score_round(Rochambeau::Paper, Rochambeau::Rock), 1);
+        assert_eq!(score_round(Rochambeau::Scissors, Rochambeau::Scissors), 6);
+    }
+
+    #[test]
+    fn test_example_data_part_2() {
+        assert_eq!(score_round_for_end(Rochambeau::Rock, End::Draw), 4);
+        assert_eq!(score_round_for_end(Rochambeau::Paper, End::Lose), 1);
+        assert_eq!(score_round_for_end(Rochambeau::Scissors, End::Win), 7);
+    }
+
+    #[test]
+    fn test_score_strategy() {
+        let guide = "A Y\nB X\nC Z\n";
+
+        let guide = StrategyGuide::parse(guide).unwrap();
+
+        assert_eq!(guide.score(), Ok(15));
+        assert_eq!(guide.score_for_end(), Ok(12));
+    }
+
+    #[test]
+    fn test_parse_round_errors() {
+        let rules = Ruleset::default();
+        assert_eq!(
+            rules.parse_round("A").unwrap_err(),
+            ParseError::new(2, "a space between the columns")
+        );
+        assert_eq!(
+            rules.parse_round("D X").unwrap_err(),
+            ParseError::new(1, "a shape of A, B or C")
+        );
+        assert_eq!(
+            StrategyGuide::parse("A Y\nB W\n")
+                .err()
+                .unwrap()
+                .to_string(),
+            "line 2, column 3: expected a shape or end of X, Y or Z"
+        );
+    }
+
+    #[test]
+    fn test_default_rules_match_the_puzzle() {
+        use Rochambeau::*;
+
+        let rules = Ruleset::default();
+        for (player, opponent, end) in [
+            (Rock, Scissors, End::Win),
+            (Paper, Rock, End::Win),
+            (Scissors, Paper, End::Win),
+            (Rock, Paper, End::Lose),
+            (Paper, Paper, End::Draw),
+        ] {
+            assert_eq!(rules.outcome(player.into(), opponent.into()), end);
+        }
+
+        assert!(matches!(Rock.choose_for_end(End::Lose), Scissors));
+        assert_eq!(Paper.play(Rock), 6);
+    }
+
+    #[test]
+    fn test_spock_lizard() {
+        let rules = Ruleset::rock_paper_scissors_spock_lizard();
+        let shape = |name| (0..5).map(Shape).find(|&s| rules.name(s) == name).unwrap();
+        let beats = |a, b| rules.outcome(shape(a), shape(b)) == End::Win;
+
+        // every rule of the game, each shape beating two and losing to two
+        assert!(beats("Scissors", "Paper"));
+        assert!(beats("Paper", "Rock"));
+        assert!(beats("Rock", "Lizard"));
+        assert!(beats("Lizard", "Spock"));
+        assert!(beats("Spock", "Scissors"));
+        assert!(beats("Scissors", "Lizard"));
+        assert!(beats("Lizard", "Paper"));
+        assert!(beats("Paper", "Spock"));
+        assert!(beats("Spock", "Rock"));
+        assert!(beats("Rock", "Scissors"));
+        assert!(!beats("Rock", "Spock"));
+
+        let rules = rules.with_points(2, 1, 0);
+        let guide = StrategyGuide::parse_with("A V\nE Z\nD W\n", rules).unwrap();
+
+        // rock draws with rock, lizard draws with lizard, and paper disproves spock
+        assert_eq!(guide.score(), Ok((1 + 1) + (1 + 5) + (2 + 2)));
+        assert!(guide.score_for_end().is_err());
+
+        assert!(Ruleset::new(&[("Rock", 1), ("Paper", 2)]).is_err());
+        assert!(Ruleset::default().with_player_letters(&["R", "P"]).is_err());
+    }
+
+    #[test]
+    fn test_letters() {
+        let rules = Ruleset::default()
+            .with_opponent_letters(&["R", "P", "S"])
+            .unwrap()
+            .with_end_letters("L", "D", "W")
+            .unwrap();
+        let guide = StrategyGuide::parse_with("R W\nP L\nS D\n", rules).unwrap();
+        assert_eq!(guide.score_for_end(), Ok((2 + 6) + 1 + (3 + 3)));
+
+        assert_eq!(
+            Ruleset::default().with_end_letters("L", "D", "L"),
+            Err("L stands for more than one thing".to_string())
+        );
+        assert_eq!(
+            Ruleset::default().with_end_letters("L", "", "W"),
+            Err("\"\" can't be read as a letter of the guide".to_string())
+        );
+        assert!(Ruleset::default()
+            .with_end_letters("L", "D", "W W")
+            .is_err());
+        assert!(Ruleset::default()
+            .with_player_letters(&["X", "Y", "X"])
+            .is_err());
+    }
+
+    #[test]
+    fn test_best_score_and_report() {
+        let guide = StrategyGuide::parse("A Y\nB X\nC Z\n").unwrap();
+        let rules = &guide.rules;
+
+        // paper beats rock, scissors beats paper, and rock beats scissors, each a win
+        assert_eq!(guide.best_score(), (2 + 6) + (3 + 6) + (1 + 6));
+        assert_eq!(
+            rules.best_play(Rochambeau::Rock.into()),
+            Rochambeau::Paper.into()
+        );
+
+        let report = guide.report();
+        assert_eq!(report.len(), 3);
+
+        let (play, total) = report[1].as_shape.unwrap();
+        assert_eq!(rules.name(play.shape), "Rock");
+        assert_eq!((play.outcome, play.score, total), (End::Lose, 1, 9));
+
+        let (play, total) = report[2].as_end.unwrap();
+        assert_eq!(rules.name(play.shape), "Rock");
+        assert_eq!((play.outcome, play.score, total), (End::Win, 7, 12));
+
+        assert_eq!(report[2].best.1, guide.best_score());
+        assert_eq!(report[2].as_shape.unwrap().1, guide.score().unwrap());
+        assert_eq!(End::Lose.to_string(), "loss");
+    }
+}
@@ -0,0 +1,303 @@
+//! --- Day 3: Rucksack Reorganization ---
+
+use std::error::Error;
+use std::fmt;
+
+use crate::parsing::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub fn priority(ch: u8) -> usize {
+    if ch.is_ascii_lowercase() {
+        (ch - b'a' + 1) as usize
+    } else {
+        (ch - b'A' + 27) as usize
+    }
+}
+
+/// A set of items, as a bit for each priority from 1 to 52
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Items(u64);
+
+impl Items {
+    /// Every item, a to z and A to Z
+    pub const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    /// The set of the items, or the position of the first byte that isn't an item
+    pub fn from_bytes(items: &[u8]) -> Result<Self, usize> {
+        items
+            .iter()
+            .enumerate()
+            .try_fold(Items(0), |set, (i, &ch)| {
+                if ch.is_ascii_alphabetic() {
+                    Ok(Items(set.0 | 1 << priority(ch)))
+                } else {
+                    Err(i)
+                }
+            })
+    }
+
+    pub fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// The priority of each item in the set, lowest first
+    pub fn priorities(self) -> impl Iterator<Item = usize> {
+        (1..=52).filter(move |priority| self.0 & 1 << priority != 0)
+    }
+
+    /// The sum of the priorities of the items in the set
+    pub fn total(self) -> usize {
+        self.priorities().sum()
+    }
+}
+
+/// A rucksack's two compartments, each holding half of its items
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    pub first: Items,
+    pub second: Items,
+}
+
+impl Rucksack {
+    // vJrwpWtwJgWrhcsFMMfFFhFp
+    pub fn parse(line: &[u8]) -> Result<Self, ParseError> {
+        let items = |compartment: &[u8], offset: usize| {
+            Items::from_bytes(compartment)
+                .map_err(|i| ParseError::new(offset + i + 1, "an item from a to z or A to Z"))
+        };
+
+        let (first, second) = line.split_at(line.len() / 2);
+        let rucksack = Rucksack {
+            first: items(first, 0)?,
+            second: items(second, first.len())?,
+        };
+
+        if line.len() % 2 == 1 {
+            let expected = "an even number of items, to split between the compartments";
+            return Err(ParseError::new(line.len() + 1, expected));
+        }
+
+        Ok(rucksack)
+    }
+
+    /// The items in both compartments
+    pub fn misplaced(&self) -> Items {
+        self.first.intersection(self.second)
+    }
+
+    /// Every item in the rucksack
+    pub fn items(&self) -> Items {
+        self.first.union(self.second)
+    }
+}
+
+/// The sum of the priorities of the items in both compartments of the rucksack
+pub fn score(rucksack: &[u8]) -> Result<usize, ParseError> {
+    Ok(Rucksack::parse(rucksack)?.misplaced().total())
+}
+
+/// The sum of the priorities of the items in every rucksack of the group, its badge
+pub fn badge_score(group: &[&[u8]]) -> Result<usize, ParseError> {
+    let mut badge = Items::ALL;
+    for rucksack in group {
+        badge = badge.intersection(Rucksack::parse(rucksack)?.items());
+    }
+
+    Ok(badge.total())
+}
+
+/// The rucksacks can't be split into groups of the size asked for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupError {
+    EmptyGroups,
+    /// The last group is short, with this many rucksacks
+    Incomplete {
+        size: usize,
+        last: usize,
+    },
+}
+
+impl fmt::Display for GroupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GroupError::EmptyGroups => write!(f, "a group needs at least one rucksack"),
+            GroupError::Incomplete { size, last } => write!(
+                f,
+                "the last group has {last} rucksacks, but groups have {size}"
+            ),
+        }
+    }
+}
+
+impl Error for GroupError {}
+
+pub struct Rucksacks {
+    pub rucksacks: Vec<Rucksack>,
+}
+
+impl Rucksacks {
+    /// Sum of the priorities of the item in both compartments of each rucksack
+    pub fn score(&self) -> usize {
+        self.rucksacks
+            .iter()
+            .map(|rucksack| rucksack.misplaced().total())
+            .sum()
+    }
+
+    /// Sum of the priorities of the badge shared by each group of `size` Elves
+    pub fn score_badges(&self, size: usize) -> Result<usize, GroupError> {
+        if size == 0 {
+            return Err(GroupError::EmptyGroups);
+        }
+
+        let last = self.rucksacks.len() % size;
+        if last != 0 {
+            return Err(GroupError::Incomplete { size, last });
+        }
+
+        let badges = self.rucksacks.chunks(size).map(|group| {
+            group
+                .iter()
+                .fold(Items::ALL, |badge, rucksack| {
+                    badge.intersection(rucksack.items())
+                })
+                .total()
+        });
+
+        Ok(badges.sum())
+    }
+}
+
+impl Solution for Rucksacks {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        let mut rucksacks = Vec::new();
+
+        for (number, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            let rucksack = Rucksack::parse(line.as_bytes()).map_err(|e| e.on_line(number + 1))?;
+            rucksacks.push(rucksack);
+        }
+
+        Ok(Self { rucksacks })
+    }
+
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.score().into())
+    }
+
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.score_badges(3)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn test_priority() {
+        assert_eq!(priority(b'a'), 1);
+        assert_eq!(priority(b'b'), 2);
+        assert_eq!(priority(b'z'), 26);
+
+        assert_eq!(priority(b'A'), 27);
+        assert_eq!(priority(b'B'), 28);
+        assert_eq!(priority(b'Z'), 52);
+    }
+
+    #[test]
+    fn test_example_part1() {
+        assert_eq!(score(b"vJrwpWtwJgWrhcsFMMfFFhFp"), Ok(16));
+        assert_eq!(score(b"jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL"), Ok(38));
+        assert_eq!(score(b"PmmdzqPrVvPwwTWBwg"), Ok(42));
+        assert_eq!(score(b"wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn"), Ok(22));
+        assert_eq!(score(b"ttgJtRGJQctTZtZT"), Ok(20));
+        assert_eq!(score(b"CrZsJsPPZsGzwwsLwLmpwMDw"), Ok(19));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert_eq!(
+            badge_score(&[
+                b"vJrwpWtwJgWrhcsFMMfFFhFp",
+                b"jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+                b"PmmdzqPrVvPwwTWBwg"
+            ]),
+            Ok(18)
+        );
+        assert_eq!(
+            badge_score(&[
+                b"wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+                b"ttgJtRGJQctTZtZT",
+                b"CrZsJsPPZsGzwwsLwLmpwMDw"
+            ]),
+            Ok(52)
+        );
+    }
+
+    #[test]
+    fn test_items() {
+        let items = Items::from_bytes(b"aZza").unwrap();
+        assert_eq!(items.len(), 3);
+        assert_eq!(items.priorities().collect::<Vec<_>>(), [1, 26, 52]);
+        assert_eq!(items.total(), 79);
+        assert_eq!(Items::ALL.len(), 52);
+        assert_eq!(Items::from_bytes(b"ab1"), Err(2));
+        assert!(Items::default().is_empty());
+    }
+
+    #[test]
+    fn test_group_sizes() {
+        let rucksacks = Rucksacks::parse(INPUT).unwrap();
+        assert_eq!(rucksacks.score_badges(3), Ok(70));
+        // no item is in all six, so the one group has no badge
+        assert_eq!(rucksacks.score_badges(6), Ok(0));
+        assert_eq!(
+            rucksacks.score_badges(1),
+            Ok(rucksacks.rucksacks.iter().map(|r| r.items().total()).sum())
+        );
+        assert_eq!(
+            rucksacks.score_badges(4),
+            Err(GroupError::Incomplete { size: 4, last: 2 })
+        );
+        assert_eq!(rucksacks.score_badges(0), Err(GroupError::EmptyGroups));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Rucksacks::parse("abcd\nabc\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected an even number of items, to split between the compartments"
+        );
+
+        let error = Rucksacks::parse("ab-d\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: expected an item from a to z or A to Z"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+//! --- Day 4: Camp Cleanup ---
+//! Space needs to be cleared before the last supplies can be unloaded from the ships, and so several Elves have been assigned the job of cleaning up sections of the camp. Every section has a unique ID number, and each Elf is assigned a range of section IDs.
+//!
+//! However, as some of the Elves compare their section assignments with each other, they've noticed that many of the assignments overlap. To try to quickly find overlaps and reduce duplicated effort, the Elves pair up and make a big list of the section assignments for each pair (your puzzle input).
+//!
+//! For example, consider the following list of section assignment pairs:
+//!
+//! 2-4,6-8
+//! 2-3,4-5
+//! 5-7,7-9
+//! 2-8,3-7
+//! 6-6,4-6
+//! 2-6,4-8
+//! For the first few pairs, this list means:
+//!
+//! Within the first pair of Elves, the first Elf was assigned sections 2-4 (sections 2, 3, and 4), while the second Elf was assigned sections 6-8 (sections 6, 7, 8).
+//! The Elves in the second pair were each assigned two sections.
+//! The Elves in the third pair were each assigned three sections: one got sections 5, 6, and 7, while the other also got 7, plus 8 and 9.
+//! This example list uses single-digit section IDs to make it easier to draw; your actual list might contain larger numbers. Visually, these pairs of section assignments look like this:
+//!
+//! .234.....  2-4
+//! .....678.  6-8
+//!
+//! .23......  2-3
+//! ...45....  4-5
+//!
+//! ....567..  5-7
+//! ......789  7-9
+//!
+//! .2345678.  2-8
+//! ..34567..  3-7
+//!
+//! .....6...  6-6
+//! ...456...  4-6
+//!
+//! .23456...  2-6
+//! ...45678.  4-8
+//! Some of the pairs have noticed that one of their assignments fully contains the other. For example, 2-8 fully contains 3-7, and 6-6 is fully contained by 4-6. In pairs where one assignment fully contains the other, one Elf in the pair would be exclusively cleaning sections their partner will already be cleaning, so these seem like the most in need of reconsideration. In this example, there are 2 such pairs.
+//!
+//! In how many assignment pairs does one range fully contain the other?
+//!
+//! --- Part Two ---
+//! It seems like there is still quite a bit of duplicate work planned. Instead, the Elves would like to know the number of pairs that overlap at all.
+//!
+//! In the above example, the first two pairs (2-4,6-8 and 2-3,4-5) don't overlap, while the remaining four pairs (5-7,7-9, 2-8,3-7, 6-6,4-6, and 2-6,4-8) do overlap:
+//!
+//! 5-7,7-9 overlaps in a single section, 7.
+//! 2-8,3-7 overlaps all of the sections 3 through 7.
+//! 6-6,4-6 overlaps in a single section, 6.
+//! 2-6,4-8 overlaps in sections 4, 5, and 6.
+//! So, in this example, the number of overlapping assignment pairs is 4.
+//!
+//! In how many assignment pairs do the ranges overlap?
+//!
+
+use std::error::Error;
+use std::ops::RangeInclusive;
+
+use nom::{combinator::map, sequence::separated_pair};
+
+use crate::intervals::IntervalSet;
+use crate::parsing::{self, parse_all, tag, IResult, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub fn either_contains_full(clean1: RangeInclusive<usize>, clean2: RangeInclusive<usize>) -> bool {
+    (clean1.contains(clean2.start()) && clean1.contains(clean2.end()))
+        || (clean2.contains(clean1.start()) && clean2.contains(clean1.end()))
+}
+
+pub fn either_contains_any(clean1: RangeInclusive<usize>, clean2: RangeInclusive<usize>) -> bool {
+    (clean1.contains(clean2.start()) || clean1.contains(clean2.end()))
+        || (clean2.contains(clean1.start()) || clean2.contains(clean1.end()))
+}
+
+// 2-4
+pub fn parse_range(input: &str) -> IResult<'_, RangeInclusive<usize>> {
+    map(
+        separated_pair(parsing::usize, tag("-"), parsing::usize),
+        |(start, end)| start..=end,
+    )(input)
+}
+
+// 2-4,6-8
+pub fn parse_line(
+    line: &str,
+) -> Result<(RangeInclusive<usize>, RangeInclusive<usize>), ParseError> {
+    parse_all(line, separated_pair(parse_range, tag(","), parse_range))
+}
+
+/// The section assignments of each pair of Elves
+pub struct Assignments {
+    pub pairs: Vec<(RangeInclusive<usize>, RangeInclusive<usize>)>,
+}
+
+impl Assignments {
+    /// Number of pairs where one assignment fully contains the other
+    pub fn count_full_overlaps(&self) -> usize {
+        self.pairs
+            .iter()
+            .filter(|(r1, r2)| either_contains_full(r1.clone(), r2.clone()))
+            .count()
+    }
+
+    /// Number of pairs where the assignments overlap at all
+    pub fn count_any_overlaps(&self) -> usize {
+        self.pairs
+            .iter()
+            .filter(|(r1, r2)| either_contains_any(r1.clone(), r2.clone()))
+            .count()
+    }
+
+    /// Every elf's assignment, two to a pair
+    pub fn elves(&self) -> impl Iterator<Item = RangeInclusive<usize>> + '_ {
+        self.pairs
+            .iter()
+            .flat_map(|(r1, r2)| [r1.clone(), r2.clone()])
+    }
+
+    /// The sections cleaned by at least one elf
+    pub fn sections(&self) -> IntervalSet<usize> {
+        self.elves().collect()
+    }
+
+    /// The sections cleaned by more than `k` elves
+    pub fn sections_cleaned_by_more_than(&self, k: usize) -> IntervalSet<usize> {
+        IntervalSet::covered_at_least(self.elves(), k + 1)
+    }
+
+    /// The sections nobody cleans, between the lowest and highest that are cleaned
+    pub fn gaps(&self) -> IntervalSet<usize> {
+        self.sections().gaps()
+    }
+}
+
+impl Solution for Assignments {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        let mut pairs = Vec::new();
+
+        for (number, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+
+            pairs.push(parse_line(line).map_err(|e| e.on_line(number + 1))?);
+        }
+
+        Ok(Self { pairs })
+    }
+
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.count_full_overlaps().into())
+    }
+
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(self.count_any_overlaps().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_part1() {
+        assert!(!either_contains_full(2..=4, 6..=8));
+        assert!(!either_contains_full(2..=3, 4..=5));
+        assert!(!either_contains_full(5..=7, 7..=9));
+        assert!(either_contains_full(2..=8, 3..=7));
+        assert!(either_contains_full(6..=6, 4..=6));
+        assert!(!either_contains_full(2..=6, 4..=8));
+
+        // made up ones
+        assert!(either_contains_full(6..=6, 6..=6));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        assert!(!either_contains_any(2..=4, 6..=8));
+        assert!(!either_contains_any(2..=3, 4..=5));
+        assert!(either_contains_any(5..=7, 7..=9));
+        assert!(either_contains_any(2..=8, 3..=7));
+        assert!(either_contains_any(6..=6, 4..=6));
+        assert!(either_contains_any(2..=6, 4..=8));
+
+        // made up ones
+        assert!(either_contains_full(6..=6, 6..=6));
+    }
+
+    #[test]
+    fn test_count_overlaps() {
+        let input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n\n";
+
+        let assignments = Assignments::parse(input).unwrap();
+        assert_eq!(assignments.pairs.len(), 6);
+
+        assert_eq!(assignments.count_full_overlaps(), 2);
+        assert_eq!(assignments.count_any_overlaps(), 4);
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n20-22,25-25\n";
+        let assignments = Assignments::parse(input).unwrap();
+
+        let sections = assignments.sections();
+        assert_eq!(sections.len(), 12);
+        assert_eq!(
+            sections.iter().collect::<Vec<_>>(),
+            [2..=9, 20..=22, 25..=25]
+        );
+        assert_eq!(
+            assignments.gaps().iter().collect::<Vec<_>>(),
+            [10..=19, 23..=24]
+        );
+        assert_eq!(assignments.sections_cleaned_by_more_than(1).len(), 7);
+        assert_eq!(assignments.sections_cleaned_by_more_than(2).len(), 1);
+        assert!(assignments.sections_cleaned_by_more_than(3).is_empty());
+    }
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(parse_line("2-4,6-8").unwrap(), (2..=4, 6..=8));
+        assert_eq!(
+            parse_line("2-4;6-8").unwrap_err(),
+            ParseError::new(4, "','")
+        );
+        assert_eq!(
+            Assignments::parse("2-4,6-8\n2-x,4-5\n")
+                .err()
+                .unwrap()
+                .to_string(),
+            "line 2, column 3: expected a number"
+        );
+    }
+}
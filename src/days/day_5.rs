@@ -0,0 +1,681 @@
+//! --- Day 5: Supply Stacks ---
+//! The expedition can depart as soon as the final supplies have been unloaded from the ships. Supplies are stored in stacks of marked crates, but because the needed supplies are buried under many other crates, the crates need to be rearranged.
+//!
+//! The ship has a giant cargo crane capable of moving crates between stacks. To ensure none of the crates get crushed or fall over, the crane operator will rearrange them in a series of carefully-planned steps. After the crates are rearranged, the desired crates will be at the top of each stack.
+//!
+//! The Elves don't want to interrupt the crane operator during this delicate procedure, but they forgot to ask her which crate will end up where, and they want to be ready to unload them as soon as possible so they can embark.
+//!
+//! They do, however, have a drawing of the starting stacks of crates and the rearrangement procedure (your puzzle input). For example:
+//!
+//!     [D]    
+//! [N] [C]    
+//! [Z] [M] [P]
+//!  1   2   3
+//!
+//! move 1 from 2 to 1
+//! move 3 from 1 to 3
+//! move 2 from 2 to 1
+//! move 1 from 1 to 2
+//! In this example, there are three stacks of crates. Stack 1 contains two crates: crate Z is on the bottom, and crate N is on top. Stack 2 contains three crates; from bottom to top, they are crates M, C, and D. Finally, stack 3 contains a single crate, P.
+//!
+//! Then, the rearrangement procedure is given. In each step of the procedure, a quantity of crates is moved from one stack to a different stack. In the first step of the above rearrangement procedure, one crate is moved from stack 2 to stack 1, resulting in this configuration:
+//!
+//! [D]        
+//! [N] [C]    
+//! [Z] [M] [P]
+//!  1   2   3
+//! In the second step, three crates are moved from stack 1 to stack 3. Crates are moved one at a time, so the first crate to be moved (D) ends up below the second and third crates:
+//!
+//!         [Z]
+//!         [N]
+//!     [C] [D]
+//!     [M] [P]
+//!  1   2   3
+//! Then, both crates are moved from stack 2 to stack 1. Again, because crates are moved one at a time, crate C ends up below crate M:
+//!
+//!         [Z]
+//!         [N]
+//! [M]     [D]
+//! [C]     [P]
+//!  1   2   3
+//! Finally, one crate is moved from stack 1 to stack 2:
+//!
+//!         [Z]
+//!         [N]
+//!         [D]
+//! [C] [M] [P]
+//!  1   2   3
+//! The Elves just need to know which crate will end up on top of each stack; in this example, the top crates are C in stack 1, M in stack 2, and Z in stack 3, so you should combine these together and give the Elves the message CMZ.
+//!
+//! After the rearrangement procedure completes, what crate ends up on top of each stack?
+//!
+//! --- Part Two ---
+//! As you watch the crane operator expertly rearrange the crates, you notice the process isn't following your prediction.
+//!
+//! Some mud was covering the writing on the side of the crane, and you quickly wipe it away. The crane isn't a CrateMover 9000 - it's a CrateMover 9001.
+//!
+//! The CrateMover 9001 is notable for many new and exciting features: air conditioning, leather seats, an extra cup holder, and the ability to pick up and move multiple crates at once.
+//!
+//! Again considering the example above, the crates begin in the same configuration:
+//!
+//!     [D]    
+//! [N] [C]    
+//! [Z] [M] [P]
+//!  1   2   3
+//! Moving a single crate from stack 2 to stack 1 behaves the same as before:
+//!
+//! [D]        
+//! [N] [C]    
+//! [Z] [M] [P]
+//!  1   2   3
+//! However, the action of moving three crates from stack 1 to stack 3 means that those three moved crates stay in the same order, resulting in this new configuration:
+//!
+//!         [D]
+//!         [N]
+//!     [C] [Z]
+//!     [M] [P]
+//!  1   2   3
+//! Next, as both crates are moved from stack 2 to stack 1, they retain their order as well:
+//!
+//!         [D]
+//!         [N]
+//! [C]     [Z]
+//! [M]     [P]
+//!  1   2   3
+//! Finally, a single crate is still moved from stack 1 to stack 2, but now it's crate C that gets moved:
+//!
+//!         [D]
+//!         [N]
+//!         [Z]
+//! [M] [C] [P]
+//!  1   2   3
+//! In this example, the CrateMover 9001 has put the crates in a totally different order: MCD.
+//!
+//! Before the rearrangement process finishes, update your simulation so that the Elves know where they should stand to be ready to unload the final supplies. After the rearrangement procedure completes, what crate ends up on top of each stack?
+//!
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+use std::ops::Range;
+
+use nom::sequence::preceded;
+
+use crate::parsing::{self, parse_all, tag, Expected, IResult, ParseError};
+use crate::solution::{Answer, Solution};
+
+/// Each stack of crates, bottom to top
+pub type Stacks = Vec<Vec<u8>>;
+
+/// The columns each stack's number spans on the line under the drawing, counting from 0
+///
+/// The stacks must be numbered in order from 1, though the numbers can be any width and spacing.
+pub fn parse_stack_numbers(s: &str) -> Result<Vec<Range<usize>>, ParseError> {
+    let mut numbers = Vec::new();
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((start, ch)) = chars.next() {
+        if ch.is_whitespace() {
+            continue;
+        }
+
+        let mut end = start + ch.len_utf8();
+        while let Some(&(offset, ch)) = chars.peek().filter(|(_, ch)| !ch.is_whitespace()) {
+            end = offset + ch.len_utf8();
+            chars.next();
+        }
+
+        let expected = numbers.len() + 1;
+        if s[start..end].parse() != Ok(expected) {
+            return Err(ParseError::new(
+                start + 1,
+                format!("stack number {expected}"),
+            ));
+        }
+        numbers.push(start..end);
+    }
+
+    if numbers.is_empty() {
+        return Err(ParseError::new(s.len() + 1, "a stack number"));
+    }
+
+    Ok(numbers)
+}
+
+/// The stack whose number is nearest the column, if it's within a column of it
+fn stack_at(numbers: &[Range<usize>], column: usize) -> Option<usize> {
+    let distance = |number: &Range<usize>| {
+        if column < number.start {
+            number.start - column
+        } else {
+            (column + 1).saturating_sub(number.end)
+        }
+    };
+
+    numbers
+        .iter()
+        .enumerate()
+        .map(|(stack, number)| (distance(number), stack))
+        .min()
+        .filter(|&(distance, _)| distance <= 1)
+        .map(|(_, stack)| stack)
+}
+
+/// The crate on each stack in a row of the drawing, `[A] [B]`, each above its stack's number
+///
+/// Rows can stop at their last crate, or run on with spaces.
+pub fn parse_stack_row(
+    line: &str,
+    numbers: &[Range<usize>],
+) -> Result<Vec<Option<u8>>, ParseError> {
+    let mut row = vec![None; numbers.len()];
+    let bytes = line.as_bytes();
+
+    let mut offset = 0;
+    while offset < bytes.len() {
+        match bytes[offset] {
+            b' ' | b'\t' | b'\r' => offset += 1,
+            b'[' => {
+                let label = bytes
+                    .get(offset + 1)
+                    .filter(|label| label.is_ascii_alphabetic())
+                    .ok_or_else(|| ParseError::new(offset + 2, "a crate letter"))?;
+                if bytes.get(offset + 2) != Some(&b']') {
+                    return Err(ParseError::new(offset + 3, "']'"));
+                }
+
+                let stack = stack_at(numbers, offset + 1)
+                    .ok_or_else(|| ParseError::new(offset + 1, "a crate above a stack number"))?;
+                if row[stack].replace(*label).is_some() {
+                    return Err(ParseError::new(offset + 1, "one crate per stack in a row"));
+                }
+
+                offset += 3;
+            }
+            _ => return Err(ParseError::new(offset + 1, "'['")),
+        }
+    }
+
+    Ok(row)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+// the number of a stack, counting from 1, as its index
+fn parse_stack_number(input: &str) -> IResult<'_, usize> {
+    match parsing::usize(input)? {
+        (rest, number) if number > 0 => Ok((rest, number - 1)),
+        _ => Err(nom::Err::Error(Expected {
+            input,
+            expected: "a stack number from 1".to_string(),
+        })),
+    }
+}
+
+// move 1 from 2 to 3
+pub fn parse_move(input: &str) -> IResult<'_, Move> {
+    let (input, count) = preceded(tag("move "), parsing::usize)(input)?;
+    // our indexes are 0 indexed
+    let (input, from) = preceded(tag(" from "), parse_stack_number)(input)?;
+    let (input, to) = preceded(tag(" to "), parse_stack_number)(input)?;
+
+    Ok((input, Move { count, from, to }))
+}
+
+/// Check the move is between stacks that exist and takes no more crates than its stack holds,
+/// updating the stacks' `heights` as though it were made
+///
+/// `line` is the move as written, to point the error at the number that's wrong.
+pub fn check_move(line: &str, mov: &Move, heights: &mut [usize]) -> Result<(), ParseError> {
+    // the column of each word of `move 1 from 2 to 3`
+    let columns: Vec<usize> = line
+        .split(' ')
+        .scan(1, |column, word| {
+            let start = *column;
+            *column += word.len() + 1;
+            Some(start)
+        })
+        .collect();
+
+    let stacks = heights.len();
+    for (stack, column) in [(mov.from, columns[3]), (mov.to, columns[5])] {
+        if stack >= stacks {
+            return Err(ParseError::new(
+                column,
+                format!("a stack number from 1 to {stacks}"),
+            ));
+        }
+    }
+
+    let held = heights[mov.from];
+    if mov.count > held {
+        return Err(ParseError::new(
+            columns[1],
+            format!("a count up to {held}, the height of stack {}", mov.from + 1),
+        ));
+    }
+
+    heights[mov.from] -= mov.count;
+    heights[mov.to] += mov.count;
+    Ok(())
+}
+
+/// Parses the drawing of the starting stacks and the rearrangement procedure
+///
+/// The drawing can have any number of stacks, and the moves are checked against it so that
+/// none take more crates than are on their stack when they're made.
+pub fn parse_crates(reader: impl BufRead) -> Result<(Stacks, Vec<Move>), Box<dyn Error>> {
+    // read all the stacks into a vec, we want to start processing on the final line.
+    let mut stack_lines = Vec::<String>::new();
+    let mut lines = reader.lines();
+
+    for line in lines.by_ref() {
+        let line = line?;
+        if line.trim().is_empty() {
+            // there is a new line separator from the stacks to the next
+            break;
+        }
+
+        stack_lines.push(line);
+    }
+
+    // the moves follow the drawing and the blank line after it
+    let moves_start = stack_lines.len() + 2;
+
+    // now pull all the stacks, pop()ing will be bottom up.
+    // first the stack numbers
+    let numbers = stack_lines.pop().ok_or("no drawing of the stacks")?;
+    let numbers = parse_stack_numbers(&numbers).map_err(|e| e.on_line(stack_lines.len() + 1))?;
+    let mut stacks: Stacks = vec![Vec::new(); numbers.len()];
+
+    // fill the stacks from the bottom row up, every crate resting on another or the floor
+    for (height, line) in stack_lines.iter().rev().enumerate() {
+        let line_number = stack_lines.len() - height;
+        let row = parse_stack_row(line, &numbers).map_err(|e| e.on_line(line_number))?;
+
+        for (stack, label) in row.into_iter().enumerate() {
+            let Some(label) = label else { continue };
+            if stacks[stack].len() != height {
+                let column = numbers[stack].start + 1;
+                return Err(ParseError::new(column, "a crate under this one")
+                    .on_line(line_number)
+                    .into());
+            }
+            stacks[stack].push(label);
+        }
+    }
+
+    // collect all the moves, checking each against the stacks as they will be
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    let mut moves = Vec::new();
+    for (number, line) in lines.enumerate() {
+        let line = line?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mov = parse_all(line, parse_move)
+            .and_then(|mov| check_move(line, &mov, &mut heights).map(|_| mov))
+            .map_err(|e| e.on_line(moves_start + number))?;
+        moves.push(mov);
+    }
+
+    Ok((stacks, moves))
+}
+
+/// A model of crane, which makes each move as one or more lifts
+///
+/// The crates in a lift keep their order as they're set down, so a crane that takes them one at
+/// a time reverses them. New models, or limits on a model, only need to say how they break up a
+/// move with [`Crane::lifts`].
+pub trait Crane {
+    /// The crane's model, like `CrateMover 9000`
+    fn name(&self) -> String;
+
+    /// The lifts the crane makes for the move, by default all the crates at once
+    fn lifts(&self, mov: Move) -> Vec<Move> {
+        vec![mov]
+    }
+
+    /// Make the move, which must take no more crates than its stack holds
+    fn make_move(&self, stacks: &mut [Vec<u8>], mov: Move) {
+        for lift in self.lifts(mov) {
+            let split_at_idx = stacks[lift.from].len() - lift.count;
+            let taken = stacks[lift.from].split_off(split_at_idx);
+            stacks[lift.to].extend(taken);
+        }
+    }
+
+    /// Make every move in turn
+    fn rearrange(&self, stacks: &mut [Vec<u8>], moves: &[Move]) {
+        for mov in moves {
+            self.make_move(stacks, *mov);
+        }
+    }
+}
+
+/// The CrateMover 9000 moves crates one at a time
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        "CrateMover 9000".to_string()
+    }
+
+    fn lifts(&self, mov: Move) -> Vec<Move> {
+        vec![Move { count: 1, ..mov }; mov.count]
+    }
+}
+
+/// The CrateMover 9001 moves multiple crates at once, keeping their order
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        "CrateMover 9001".to_string()
+    }
+}
+
+/// Another crane that can lift no more than `capacity` crates at once, splitting its larger
+/// lifts and taking the top crates first
+pub struct Limited<C> {
+    pub crane: C,
+    pub capacity: usize,
+}
+
+impl<C: Crane> Crane for Limited<C> {
+    fn name(&self) -> String {
+        format!("{} lifting up to {}", self.crane.name(), self.capacity)
+    }
+
+    fn lifts(&self, mov: Move) -> Vec<Move> {
+        let capacity = self.capacity.max(1);
+
+        self.crane
+            .lifts(mov)
+            .into_iter()
+            .flat_map(|lift| {
+                let full = lift.count / capacity;
+                let rest = lift.count % capacity;
+                let mut lifts = vec![
+                    Move {
+                        count: capacity,
+                        ..lift
+                    };
+                    full
+                ];
+                if rest > 0 {
+                    lifts.push(Move {
+                        count: rest,
+                        ..lift
+                    });
+                }
+                lifts
+            })
+            .collect()
+    }
+}
+
+impl<C: Crane + ?Sized> Crane for Box<C> {
+    fn name(&self) -> String {
+        (**self).name()
+    }
+
+    fn lifts(&self, mov: Move) -> Vec<Move> {
+        (**self).lifts(mov)
+    }
+}
+
+/// Draw the stacks as the puzzle does, a row of `[X]` crates per level above the stack numbers
+pub fn draw_stacks(stacks: &[Vec<u8>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut drawing = String::new();
+
+    for level in (0..height).rev() {
+        let row: Vec<_> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(&label) => format!("[{}]", label as char),
+                None => "   ".to_string(),
+            })
+            .collect();
+        drawing.push_str(&row.join(" "));
+        drawing.push('\n');
+    }
+
+    let numbers: Vec<_> = (1..=stacks.len()).map(|n| format!("{n:^3}")).collect();
+    drawing.push_str(&numbers.join(" "));
+    drawing.push('\n');
+
+    drawing
+}
+
+/// The crate on the top of each stack, skipping the stacks left empty
+pub fn top_of_stacks(columns: &[Vec<u8>]) -> Result<String, Box<dyn Error>> {
+    let top_of_stacks: Vec<u8> = columns
+        .iter()
+        .filter_map(|stack| stack.last().copied())
+        .collect();
+
+    Ok(String::from_utf8(top_of_stacks)?)
+}
+
+/// The starting stacks of crates and the rearrangement procedure
+pub struct Cargo {
+    pub stacks: Stacks,
+    pub moves: Vec<Move>,
+}
+
+impl Solution for Cargo {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        let (stacks, moves) = parse_crates(input.as_bytes())?;
+        Ok(Self { stacks, moves })
+    }
+
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        let mut stacks = self.stacks.clone();
+        CrateMover9000.rearrange(&mut stacks, &self.moves);
+        Ok(top_of_stacks(&stacks)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        let mut stacks = self.stacks.clone();
+        CrateMover9001.rearrange(&mut stacks, &self.moves);
+        Ok(top_of_stacks(&stacks)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stack_numbers() {
+        let numbers = parse_stack_numbers(" 1   2   3 ").unwrap();
+        assert_eq!(numbers, [1..2, 5..6, 9..10]);
+
+        let numbers = parse_stack_numbers("1 2 3 4 5 6 7 8 9 10 11").unwrap();
+        assert_eq!(numbers[9..], [18..20, 21..23]);
+
+        assert_eq!(
+            parse_stack_numbers(" 1   3").unwrap_err(),
+            ParseError::new(6, "stack number 2")
+        );
+        assert_eq!(
+            parse_stack_numbers("[A]").unwrap_err(),
+            ParseError::new(1, "stack number 1")
+        );
+    }
+
+    #[test]
+    fn test_parse_stack_row() {
+        let numbers = parse_stack_numbers(" 1   2   3   4").unwrap();
+        let row = parse_stack_row("[D]     [E]", &numbers).unwrap();
+        assert_eq!(row, [Some(b'D'), None, Some(b'E'), None]);
+
+        assert_eq!(
+            parse_stack_row("[D] [1]", &numbers).unwrap_err(),
+            ParseError::new(6, "a crate letter")
+        );
+        assert_eq!(
+            parse_stack_row("  [D]", &numbers).unwrap_err(),
+            ParseError::new(3, "a crate above a stack number")
+        );
+    }
+
+    #[test]
+    fn test_parse_move() {
+        let mov = parse_move("move 1 from 2 to 3").unwrap().1;
+
+        assert_eq!(mov.count, 1);
+        assert_eq!(mov.from, 1);
+        assert_eq!(mov.to, 2);
+
+        assert_eq!(
+            parse_all("move 1 from 0 to 3", parse_move).unwrap_err(),
+            ParseError::new(13, "a stack number from 1")
+        );
+        assert_eq!(
+            parse_all("move 1 to 3", parse_move).unwrap_err(),
+            ParseError::new(7, "' from '")
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let input = r#"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2"#;
+
+        let (columns, moves) = parse_crates(input.as_bytes()).unwrap();
+        assert_eq!(columns, [b"ZN".to_vec(), b"MCD".to_vec(), b"P".to_vec()]);
+
+        let mut part1 = columns.clone();
+        CrateMover9000.rearrange(&mut part1, &moves);
+        assert_eq!(top_of_stacks(&part1).unwrap(), "CMZ");
+
+        let mut part2 = columns.clone();
+        CrateMover9001.rearrange(&mut part2, &moves);
+        assert_eq!(top_of_stacks(&part2).unwrap(), "MCD");
+
+        // lifting two at a time, the second move sets down the top two crates and then the one
+        // under them, so it ends on Z
+        let mut limited = columns;
+        let crane = Limited {
+            crane: CrateMover9001,
+            capacity: 2,
+        };
+        crane.rearrange(&mut limited, &moves);
+        assert_eq!(top_of_stacks(&limited).unwrap(), "MCZ");
+    }
+
+    #[test]
+    fn test_many_stacks() {
+        // trimmed lines and two digit stack numbers
+        let input = "                                        [K]
+[A] [B] [C] [D] [E] [F] [G] [H] [I] [J] [L]
+ 1   2   3   4   5   6   7   8   9  10  11
+
+move 2 from 11 to 10
+move 1 from 10 to 1
+";
+
+        let (mut stacks, moves) = parse_crates(input.as_bytes()).unwrap();
+        assert_eq!(stacks.len(), 11);
+        assert_eq!(stacks[10], b"LK");
+
+        CrateMover9000.rearrange(&mut stacks, &moves);
+        assert!(stacks[10].is_empty());
+        assert_eq!(top_of_stacks(&stacks).unwrap(), "LBCDEFGHIK");
+
+        let cargo = Cargo::parse(input).unwrap();
+        assert_eq!(
+            cargo.part1().unwrap(),
+            Answer::Text("LBCDEFGHIK".to_string())
+        );
+    }
+
+    #[test]
+    fn test_malformed() {
+        let error = |input: &str| parse_crates(input.as_bytes()).err().unwrap().to_string();
+
+        assert_eq!(
+            error("    [A]\n[B]\n 1   2\n\nmove 1 from 1 to 2\n"),
+            "line 1, column 6: expected a crate under this one"
+        );
+        assert_eq!(
+            error("[A]\n 1   2\n\nmove 2 from 1 to 2\n"),
+            "line 4, column 6: expected a count up to 1, the height of stack 1"
+        );
+        assert_eq!(
+            error("[A]\n 1   2\n\nmove 1 from 1 to 2\nmove 1 from 1 to 2\n"),
+            "line 5, column 6: expected a count up to 0, the height of stack 1"
+        );
+        assert_eq!(
+            error("[A]\n 1   2\n\nmove 1 from 1 to 3\n"),
+            "line 4, column 18: expected a stack number from 1 to 2"
+        );
+        assert_eq!(error("\nmove 1 from 1 to 2\n"), "no drawing of the stacks");
+    }
+
+    #[test]
+    fn test_lifts() {
+        let mov = Move {
+            count: 5,
+            from: 0,
+            to: 1,
+        };
+        let lift = |count| Move { count, ..mov };
+
+        assert_eq!(CrateMover9000.lifts(mov), [lift(1); 5]);
+        assert_eq!(CrateMover9001.lifts(mov), [mov]);
+
+        let crane: Box<dyn Crane> = Box::new(Limited {
+            crane: CrateMover9001,
+            capacity: 2,
+        });
+        assert_eq!(crane.lifts(mov), [lift(2), lift(2), lift(1)]);
+        assert_eq!(crane.name(), "CrateMover 9001 lifting up to 2");
+        assert_eq!(mov.to_string(), "move 5 from 1 to 2");
+    }
+
+    #[test]
+    fn test_draw_stacks() {
+        let drawing = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n";
+        let (stacks, _) = parse_crates(format!("{drawing}\n").as_bytes()).unwrap();
+        assert_eq!(draw_stacks(&stacks), drawing);
+
+        // two digit numbers stay under their stacks, so the drawing reads back the same
+        let mut stacks = vec![Vec::new(); 10];
+        stacks[9].push(b'X');
+        let drawing = draw_stacks(&stacks);
+        assert_eq!(
+            parse_crates(format!("{drawing}\n").as_bytes()).unwrap().0,
+            stacks
+        );
+    }
+}
@@ -0,0 +1,227 @@
+//! --- Day 6: Tuning Trouble ---
+//! The preparations are finally complete; you and the Elves leave camp on foot and begin to make your way toward the star fruit grove.
+//!
+//! As you move through the dense undergrowth, one of the Elves gives you a handheld device. He says that it has many fancy features, but the most important one to set up right now is the communication system.
+//!
+//! However, because he's heard you have significant experience dealing with signal-based systems, he convinced the other Elves that it would be okay to give you their one malfunctioning device - surely you'll have no problem fixing it.
+//!
+//! As if inspired by comedic timing, the device emits a few colorful sparks.
+//!
+//! To be able to communicate with the Elves, the device needs to lock on to their signal. The signal is a series of seemingly-random characters that the device receives one at a time.
+//!
+//! To fix the communication system, you need to add a subroutine to the device that detects a start-of-packet marker in the datastream. In the protocol being used by the Elves, the start of a packet is indicated by a sequence of four characters that are all different.
+//!
+//! The device will send your subroutine a datastream buffer (your puzzle input); your subroutine needs to identify the first position where the four most recently received characters were all different. Specifically, it needs to report the number of characters from the beginning of the buffer to the end of the first such four-character marker.
+//!
+//! For example, suppose you receive the following datastream buffer:
+//!
+//! mjqjpqmgbljsphdztnvjfqwrcgsmlb
+//! After the first three characters (mjq) have been received, there haven't been enough characters received yet to find the marker. The first time a marker could occur is after the fourth character is received, making the most recent four characters mjqj. Because j is repeated, this isn't a marker.
+//!
+//! The first time a marker appears is after the seventh character arrives. Once it does, the last four characters received are jpqm, which are all different. In this case, your subroutine should report the value 7, because the first start-of-packet marker is complete after 7 characters have been processed.
+//!
+//! Here are a few more examples:
+//!
+//! bvwbjplbgvbhsrlpgdmjqwftvncz: first marker after character 5
+//! nppdvjthqldpwncqszvftbrmjlhg: first marker after character 6
+//! nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg: first marker after character 10
+//! zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw: first marker after character 11
+//! How many characters need to be processed before the first start-of-packet marker is detected?
+//!
+//!
+//! --- Part Two ---
+//! Your device's communication system is correctly detecting packets, but still isn't working. It looks like it also needs to look for messages.
+//!
+//! A start-of-message marker is just like a start-of-packet marker, except it consists of 14 distinct characters rather than 4.
+//!
+//! Here are the first positions of start-of-message markers for all of the above examples:
+//!
+//! mjqjpqmgbljsphdztnvjfqwrcgsmlb: first marker after character 19
+//! bvwbjplbgvbhsrlpgdmjqwftvncz: first marker after character 23
+//! nppdvjthqldpwncqszvftbrmjlhg: first marker after character 23
+//! nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg: first marker after character 29
+//! zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw: first marker after character 26
+//! How many characters need to be processed before the first start-of-message marker is detected?
+//!
+
+use std::error::Error;
+use std::io::{self, BufReader, Bytes, Read};
+use std::iter::FusedIterator;
+use std::num::NonZeroUsize;
+
+use crate::solution::{Answer, Solution};
+
+pub const SINGAL_LEN: NonZeroUsize = NonZeroUsize::new(4).unwrap();
+pub const MESSAGE_LEN: NonZeroUsize = NonZeroUsize::new(14).unwrap();
+
+/// The markers in a datastream, read a byte at a time
+///
+/// A marker is the position, counting characters from 1, just after a window of distinct bytes.
+/// Every position a window ends is checked in constant time by remembering where each byte was
+/// last seen, so the stream is only read once. The datastream ends at the end of the reader, or
+/// of its first line, and nothing more is read after that.
+pub struct Markers<R> {
+    bytes: Bytes<BufReader<R>>,
+    window: NonZeroUsize,
+    /// The position after each byte's last appearance, or 0 if it hasn't been seen
+    last_seen: [usize; 256],
+    /// Where the run of distinct bytes ending at `position` starts
+    run_start: usize,
+    /// How many bytes have been read
+    position: usize,
+    /// Whether the end of the datastream has been reached
+    done: bool,
+}
+
+impl<R: Read> Markers<R> {
+    /// Find the markers after each window of `window` distinct bytes
+    pub fn new(reader: R, window: NonZeroUsize) -> Self {
+        Self {
+            bytes: BufReader::new(reader).bytes(),
+            window,
+            last_seen: [0; 256],
+            run_start: 0,
+            position: 0,
+            done: false,
+        }
+    }
+}
+
+impl<R: Read> Iterator for Markers<R> {
+    type Item = io::Result<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let byte = match self.bytes.next() {
+                Some(Ok(b'\n' | b'\r')) | None => break,
+                Some(Ok(byte)) => byte,
+                Some(Err(e)) => return Some(Err(e)),
+            };
+
+            // the run can't reach back past this byte's last appearance
+            let last_seen = &mut self.last_seen[usize::from(byte)];
+            self.run_start = self.run_start.max(*last_seen);
+            self.position += 1;
+            *last_seen = self.position;
+
+            if self.position - self.run_start >= self.window.get() {
+                return Some(Ok(self.position));
+            }
+        }
+
+        self.done = true;
+        None
+    }
+}
+
+impl<R: Read> FusedIterator for Markers<R> {}
+
+/// The first marker after `window` distinct bytes, or `None` if the datastream has none
+pub fn first_marker(reader: impl Read, window: NonZeroUsize) -> io::Result<Option<usize>> {
+    Markers::new(reader, window).next().transpose()
+}
+
+/// The datastream buffer from the communication device
+pub struct Datastream {
+    pub buffer: Vec<u8>,
+}
+
+impl Solution for Datastream {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        let buffer = input.lines().next().ok_or("no datastream")?;
+        Ok(Self {
+            buffer: buffer.as_bytes().to_vec(),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        let marker = first_marker(&self.buffer[..], SINGAL_LEN)?;
+        Ok(marker.ok_or("no start-of-packet marker")?.into())
+    }
+
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        let marker = first_marker(&self.buffer[..], MESSAGE_LEN)?;
+        Ok(marker.ok_or("no start-of-message marker")?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first(buffer: &[u8], window: NonZeroUsize) -> Option<usize> {
+        first_marker(buffer, window).unwrap()
+    }
+
+    fn window(len: usize) -> NonZeroUsize {
+        NonZeroUsize::new(len).unwrap()
+    }
+
+    #[test]
+    fn test_find_signal() {
+        assert_eq!(
+            first(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", SINGAL_LEN),
+            Some(7)
+        );
+        assert_eq!(first(b"bvwbjplbgvbhsrlpgdmjqwftvncz", SINGAL_LEN), Some(5));
+        assert_eq!(first(b"nppdvjthqldpwncqszvftbrmjlhg", SINGAL_LEN), Some(6));
+        assert_eq!(
+            first(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", SINGAL_LEN),
+            Some(10)
+        );
+        assert_eq!(
+            first(b"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", SINGAL_LEN),
+            Some(11)
+        );
+    }
+
+    #[test]
+    fn test_find_message() {
+        assert_eq!(
+            first(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", MESSAGE_LEN),
+            Some(19)
+        );
+        assert_eq!(
+            first(b"bvwbjplbgvbhsrlpgdmjqwftvncz", MESSAGE_LEN),
+            Some(23)
+        );
+        assert_eq!(
+            first(b"nppdvjthqldpwncqszvftbrmjlhg", MESSAGE_LEN),
+            Some(23)
+        );
+        assert_eq!(
+            first(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", MESSAGE_LEN),
+            Some(29)
+        );
+        assert_eq!(
+            first(b"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", MESSAGE_LEN),
+            Some(26)
+        );
+    }
+
+    #[test]
+    fn test_every_marker() {
+        let markers = |buffer: &[u8], len| {
+            Markers::new(buffer, window(len))
+                .collect::<io::Result<Vec<_>>>()
+                .unwrap()
+        };
+
+        assert_eq!(markers(b"aabcbcd", 3), [4, 7]);
+        assert_eq!(markers(b"abcd\nefgh", 4), [4]);
+        assert_eq!(markers(b"xyz", 1), [1, 2, 3]);
+        assert_eq!(markers(b"abcdefghijklmnopqrstuvwxyz", 26), [26]);
+
+        // no marker isn't an answer of 0, nor a panic at the end of the stream
+        assert_eq!(first(b"aaaaaaaaaaaaaaaaaaaaaaa", SINGAL_LEN), None);
+        assert_eq!(first(b"abc", SINGAL_LEN), None);
+        assert_eq!(first(b"", window(1)), None);
+
+        // the datastream stops at its first line, and stays stopped
+        let mut markers = Markers::new(&b"ab\ncd"[..], window(1));
+        assert_eq!(markers.next().unwrap().unwrap(), 1);
+        assert_eq!(markers.next().unwrap().unwrap(), 2);
+        assert!(markers.next().is_none());
+        assert!(markers.next().is_none());
+    }
+}
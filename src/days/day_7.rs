@@ -0,0 +1,983 @@
+//! --- Day 7: No Space Left On Device ---
+//! You can hear birds chirping and raindrops hitting leaves as the expedition proceeds. Occasionally, you can even hear much louder sounds in the distance; how big do the animals get out here, anyway?
+//!
+//! The device the Elves gave you has problems with more than just its communication system. You try to run a system update:
+//!
+//! $ system-update --please --pretty-please-with-sugar-on-top
+//! Error: No space left on device
+//! Perhaps you can delete some files to make space for the update?
+//!
+//! You browse around the filesystem to assess the situation and save the resulting terminal output (your puzzle input). For example:
+//!
+//! $ cd /
+//! $ ls
+//! dir a
+//! 14848514 b.txt
+//! 8504156 c.dat
+//! dir d
+//! $ cd a
+//! $ ls
+//! dir e
+//! 29116 f
+//! 2557 g
+//! 62596 h.lst
+//! $ cd e
+//! $ ls
+//! 584 i
+//! $ cd ..
+//! $ cd ..
+//! $ cd d
+//! $ ls
+//! 4060174 j
+//! 8033020 d.log
+//! 5626152 d.ext
+//! 7214296 k
+//! The filesystem consists of a tree of files (plain data) and directories (which can contain other directories or files). The outermost directory is called /. You can navigate around the filesystem, moving into or out of directories and listing the contents of the directory you're currently in.
+//!
+//! Within the terminal output, lines that begin with $ are commands you executed, very much like some modern computers:
+//!
+//! cd means change directory. This changes which directory is the current directory, but the specific result depends on the argument:
+//! cd x moves in one level: it looks in the current directory for the directory named x and makes it the current directory.
+//! cd .. moves out one level: it finds the directory that contains the current directory, then makes that directory the current directory.
+//! cd / switches the current directory to the outermost directory, /.
+//! ls means list. It prints out all of the files and directories immediately contained by the current directory:
+//! 123 abc means that the current directory contains a file named abc with size 123.
+//! dir xyz means that the current directory contains a directory named xyz.
+//! Given the commands and output in the example above, you can determine that the filesystem looks visually like this:
+//!
+//! - / (dir)
+//!   - a (dir)
+//!     - e (dir)
+//!       - i (file, size=584)
+//!     - f (file, size=29116)
+//!     - g (file, size=2557)
+//!     - h.lst (file, size=62596)
+//!   - b.txt (file, size=14848514)
+//!   - c.dat (file, size=8504156)
+//!   - d (dir)
+//!     - j (file, size=4060174)
+//!     - d.log (file, size=8033020)
+//!     - d.ext (file, size=5626152)
+//!     - k (file, size=7214296)
+//!
+//! Here, there are four directories: / (the outermost directory), a and d (which are in /), and e (which is in a). These directories also contain files of various sizes.
+//!
+//! Since the disk is full, your first step should probably be to find directories that are good candidates for deletion. To do this, you need to determine the total size of each directory. The total size of a directory is the sum of the sizes of the files it contains, directly or indirectly. (Directories themselves do not count as having any intrinsic size.)
+//!
+//! The total sizes of the directories above can be found as follows:
+//!
+//! The total size of directory e is 584 because it contains a single file i of size 584 and no other directories.
+//! The directory a has total size 94853 because it contains files f (size 29116), g (size 2557), and h.lst (size 62596), plus file i indirectly (a contains e which contains i).
+//! Directory d has total size 24933642.
+//! As the outermost directory, / contains every file. Its total size is 48381165, the sum of the size of every file.
+//! To begin, find all of the directories with a total size of at most 100000, then calculate the sum of their total sizes. In the example above, these directories are a and e; the sum of their total sizes is 95437 (94853 + 584). (As in this example, this process can count files more than once!)
+//!
+//! Find all of the directories with a total size of at most 100000. What is the sum of the total sizes of those directories?
+//!
+//!
+//! --- Part Two ---
+//! Now, you're ready to choose a directory to delete.
+//!
+//! The total disk space available to the filesystem is 70000000. To run the update, you need unused space of at least 30000000. You need to find a directory you can delete that will free up enough space to run the update.
+//!
+//! In the example above, the total size of the outermost directory (and thus the total amount of used space) is 48381165; this means that the size of the unused space must currently be 21618835, which isn't quite the 30000000 required by the update. Therefore, the update still requires a directory with total size of at least 8381165 to be deleted before it can run.
+//!
+//! To achieve this, you have the following options:
+//!
+//! Delete directory e, which would increase unused space by 584.
+//! Delete directory a, which would increase unused space by 94853.
+//! Delete directory d, which would increase unused space by 24933642.
+//! Delete directory /, which would increase unused space by 48381165.
+//! Directories e and a are both too small; deleting them would not free up enough space. However, directories d and / are both big enough! Between these, choose the smallest: d, increasing unused space by 24933642.
+//!
+//! Find the smallest directory that, if deleted, would free up enough space on the filesystem to run the update. What is the total size of that directory?
+//!
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs::{self, File};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use nom::{
+    branch::alt,
+    bytes::complete::take_till1,
+    character::{self, complete::space1},
+    combinator::{map, value},
+    sequence::{preceded, tuple},
+};
+
+use crate::parsing::{parse_all, tag, Expected, IResult, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub const TOTAL_DISK_SIZE: usize = 70000000;
+pub const SPARE_DISK_NEED: usize = 30000000;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Command<'a> {
+    List,
+    ChangeDirectory(Directory<'a>),
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Directory<'a> {
+    Root,
+    Parent,
+    /// A path from the root, like `/a/b`
+    Absolute(&'a str),
+    /// A path from the current directory, like `a` or `a/b`
+    Path(&'a str),
+}
+
+/// Writes the command as it's typed, like `$ cd a`
+impl Display for Command<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::List => write!(f, "$ ls"),
+            Command::ChangeDirectory(directory) => write!(f, "$ cd {directory}"),
+        }
+    }
+}
+
+impl Display for Directory<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Directory::Root => write!(f, "/"),
+            Directory::Parent => write!(f, ".."),
+            Directory::Absolute(path) | Directory::Path(path) => write!(f, "{path}"),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct FileRef<'a> {
+    pub name: &'a str,
+    pub extension: Option<&'a str>,
+    pub size: u64,
+}
+
+impl<'a> FileRef<'a> {
+    /// Split a file's name at its first dot, so `a.tar.gz` has the extension `tar.gz`
+    ///
+    /// A dot that starts or ends the name doesn't count, so `.profile` and `a.` have none.
+    pub fn new(full_name: &'a str, size: u64) -> Self {
+        let dot = full_name
+            .char_indices()
+            .skip(1)
+            .find(|&(i, ch)| ch == '.' && i + 1 < full_name.len())
+            .map(|(i, _)| i);
+
+        let (name, extension) = match dot {
+            Some(dot) => (&full_name[..dot], Some(&full_name[dot + 1..])),
+            None => (full_name, None),
+        };
+
+        Self {
+            name,
+            extension,
+            size,
+        }
+    }
+
+    /// The file's name with its extension
+    pub fn full_name(&self) -> String {
+        match self.extension {
+            Some(extension) => format!("{}.{extension}", self.name),
+            None => self.name.to_string(),
+        }
+    }
+}
+
+/// The index of a file or directory in a [`Tree`]
+pub type NodeId = usize;
+
+/// Whether a node is a directory, with the nodes in it, or a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Kind {
+    Dir { children: Vec<NodeId> },
+    File { extension: Option<String> },
+}
+
+/// A file or directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub name: String,
+    /// The directory it's in, or `None` for the root
+    pub parent: Option<NodeId>,
+    /// The file's size, or the total size of everything in the directory
+    pub size: usize,
+    pub kind: Kind,
+}
+
+impl Node {
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, Kind::Dir { .. })
+    }
+}
+
+/// A filesystem, with every node kept in one list and the root directory first
+///
+/// Each directory's size is kept up to date as files are added, so finding it is free.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tree {
+    nodes: Vec<Node>,
+}
+
+impl Default for Tree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tree {
+    pub const ROOT: NodeId = 0;
+
+    /// A tree with nothing but the root directory, `/`
+    pub fn new() -> Self {
+        let root = Node {
+            name: "/".to_string(),
+            parent: None,
+            size: 0,
+            kind: Kind::Dir {
+                children: Vec::new(),
+            },
+        };
+
+        Self { nodes: vec![root] }
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    /// How many files and directories there are, counting the root
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the tree holds nothing but an empty root
+    pub fn is_empty(&self) -> bool {
+        self.nodes.len() == 1
+    }
+
+    /// The nodes in the directory, in the order they were added
+    pub fn children(&self, dir: NodeId) -> &[NodeId] {
+        match &self.nodes[dir].kind {
+            Kind::Dir { children } => children,
+            Kind::File { .. } => &[],
+        }
+    }
+
+    /// The node in the directory with the name
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        self.children(dir)
+            .iter()
+            .copied()
+            .find(|&child| self.nodes[child].name == name)
+    }
+
+    /// Add a node to the directory, or find the one with its name already there
+    fn add(&mut self, dir: NodeId, node: Node) -> Result<NodeId, String> {
+        // names that would be read as paths can't be followed, or written out safely
+        if matches!(node.name.as_str(), "." | "..") || node.name.contains('/') {
+            return Err(format!("{} isn't a valid name", node.name));
+        }
+
+        if let Some(existing) = self.child(dir, &node.name) {
+            return if self.nodes[existing].is_dir() == node.is_dir() {
+                Ok(existing)
+            } else {
+                Err(format!(
+                    "{} is both a file and a directory",
+                    self.path(existing)
+                ))
+            };
+        }
+
+        let id = self.nodes.len();
+        match &mut self.nodes[dir].kind {
+            Kind::Dir { children } => children.push(id),
+            Kind::File { .. } => return Err(format!("{} isn't a directory", self.path(dir))),
+        }
+
+        // every directory above it grows by the node's size
+        let mut ancestor = Some(dir);
+        while let Some(id) = ancestor {
+            self.nodes[id].size += node.size;
+            ancestor = self.nodes[id].parent;
+        }
+
+        self.nodes.push(node);
+        Ok(id)
+    }
+
+    /// Add a directory to a directory, or find the one already there
+    pub fn add_dir(&mut self, dir: NodeId, name: &str) -> Result<NodeId, String> {
+        let node = Node {
+            name: name.to_string(),
+            parent: Some(dir),
+            size: 0,
+            kind: Kind::Dir {
+                children: Vec::new(),
+            },
+        };
+        self.add(dir, node)
+    }
+
+    /// Add a file to a directory, unless it's already been listed there
+    pub fn add_file(&mut self, dir: NodeId, file: &FileRef<'_>) -> Result<NodeId, String> {
+        let node = Node {
+            name: file.full_name(),
+            parent: Some(dir),
+            size: file.size as usize,
+            kind: Kind::File {
+                extension: file.extension.map(String::from),
+            },
+        };
+        self.add(dir, node)
+    }
+
+    /// Follow the path from a directory, adding any directories along it that aren't there yet
+    ///
+    /// The path's parts are separated by `/`, and `..` goes up to the parent, stopping at the root.
+    pub fn walk(&mut self, dir: NodeId, path: &str) -> Result<NodeId, String> {
+        let mut dir = dir;
+        for part in path.split('/') {
+            dir = match part {
+                "" | "." => dir,
+                ".." => self.nodes[dir].parent.unwrap_or(Self::ROOT),
+                name => self.add_dir(dir, name)?,
+            };
+        }
+
+        Ok(dir)
+    }
+
+    /// The total size of the file or directory
+    pub fn size(&self, id: NodeId) -> usize {
+        self.nodes[id].size
+    }
+
+    /// The absolute path to the node, like `/a/e`
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut node = id;
+        while let Some(parent) = self.nodes[node].parent {
+            names.push(self.nodes[node].name.as_str());
+            node = parent;
+        }
+
+        names.reverse();
+        format!("/{}", names.join("/"))
+    }
+
+    /// Every directory, the root first
+    pub fn directories(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.nodes.len()).filter(|&id| self.nodes[id].is_dir())
+    }
+
+    /// Every file
+    pub fn files(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.nodes.len()).filter(|&id| !self.nodes[id].is_dir())
+    }
+
+    /// The `n` largest directories, largest first
+    pub fn largest_directories(&self, n: usize) -> Vec<NodeId> {
+        let mut directories: Vec<_> = self.directories().collect();
+        directories.sort_by_key(|&id| (Reverse(self.size(id)), self.path(id)));
+        directories.truncate(n);
+        directories
+    }
+
+    /// The number of files with each extension and their total size, `None` for no extension
+    pub fn files_by_extension(&self) -> BTreeMap<Option<&str>, (usize, usize)> {
+        let mut extensions = BTreeMap::new();
+        for id in self.files() {
+            let Kind::File { extension } = &self.nodes[id].kind else {
+                continue;
+            };
+
+            let (count, size) = extensions.entry(extension.as_deref()).or_insert((0, 0));
+            *count += 1;
+            *size += self.nodes[id].size;
+        }
+        extensions
+    }
+
+    /// Whether the disk already has enough free for the update, without deleting anything
+    pub fn update_fits(&self, disk_size: usize, update_size: usize) -> bool {
+        self.size(Self::ROOT)
+            .checked_add(update_size)
+            .is_some_and(|needed| needed <= disk_size)
+    }
+
+    /// The smallest directory that frees enough of the disk for the update, once deleted
+    pub fn smallest_to_free(&self, disk_size: usize, update_size: usize) -> Option<NodeId> {
+        let free = disk_size.saturating_sub(self.size(Self::ROOT));
+        let needed = update_size.saturating_sub(free);
+
+        self.directories()
+            .filter(|&id| self.size(id) >= needed)
+            .min_by_key(|&id| self.size(id))
+    }
+
+    /// Read a real directory into a tree, with its subdirectories and the sizes of its files
+    ///
+    /// The entries of each directory are added by name, before any of the directories in it.
+    /// Symbolic links aren't followed, and names that aren't UTF-8 or have whitespace in them,
+    /// which a transcript can't hold, are errors.
+    pub fn from_dir(path: &Path) -> io::Result<Self> {
+        let mut tree = Self::new();
+        tree.read_dir(Self::ROOT, path)?;
+        Ok(tree)
+    }
+
+    fn read_dir(&mut self, dir: NodeId, path: &Path) -> io::Result<()> {
+        let mut entries = fs::read_dir(path)?.collect::<io::Result<Vec<_>>>()?;
+        entries.sort_by_key(|entry| entry.file_name());
+
+        let invalid = |e: String| io::Error::new(io::ErrorKind::InvalidData, e);
+        let mut subdirs = Vec::new();
+        for entry in entries {
+            let name = entry.file_name();
+            let name = name
+                .to_str()
+                .filter(|name| !name.contains(char::is_whitespace))
+                .ok_or_else(|| {
+                    invalid(format!(
+                        "{} can't be in a transcript",
+                        entry.path().display()
+                    ))
+                })?;
+
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                subdirs.push((self.add_dir(dir, name).map_err(invalid)?, entry.path()));
+            } else if file_type.is_file() {
+                let file = FileRef::new(name, entry.metadata()?.len());
+                self.add_file(dir, &file).map_err(invalid)?;
+            }
+        }
+
+        for (subdir, path) in subdirs {
+            self.read_dir(subdir, &path)?;
+        }
+        Ok(())
+    }
+
+    /// Write a terminal transcript that lists the whole tree, in the order it was added
+    ///
+    /// Replaying the transcript with [`build_tree`] builds the same tree.
+    pub fn write_transcript(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "{}", Command::ChangeDirectory(Directory::Root))?;
+        self.write_listing(out, Self::ROOT)
+    }
+
+    fn write_listing(&self, out: &mut impl Write, dir: NodeId) -> io::Result<()> {
+        writeln!(out, "{}", Command::List)?;
+
+        for &child in self.children(dir) {
+            let node = &self.nodes[child];
+            let line = match node.kind {
+                Kind::Dir { .. } => Line::Dir(&node.name),
+                Kind::File { .. } => Line::File(FileRef::new(&node.name, node.size as u64)),
+            };
+            writeln!(out, "{line}")?;
+        }
+
+        for &child in self.children(dir) {
+            let node = &self.nodes[child];
+            if node.is_dir() {
+                writeln!(
+                    out,
+                    "{}",
+                    Command::ChangeDirectory(Directory::Path(&node.name))
+                )?;
+                self.write_listing(out, child)?;
+                writeln!(out, "{}", Command::ChangeDirectory(Directory::Parent))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Create the tree's directories under `root`, with a sparse file of the right size for each
+    /// of its files
+    pub fn materialize(&self, root: &Path) -> io::Result<()> {
+        fs::create_dir_all(root)?;
+
+        for id in 1..self.nodes.len() {
+            let path = root.join(self.path(id).trim_start_matches('/'));
+
+            match self.nodes[id].kind {
+                Kind::Dir { .. } => fs::create_dir_all(path)?,
+                Kind::File { .. } => File::create(path)?.set_len(self.nodes[id].size as u64)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fmt_node(&self, f: &mut fmt::Formatter<'_>, id: NodeId, depth: usize) -> fmt::Result {
+        let node = &self.nodes[id];
+        let indent = "  ".repeat(depth);
+
+        match &node.kind {
+            Kind::Dir { children } => {
+                writeln!(f, "{indent}- {} (dir)", node.name)?;
+
+                let mut children = children.clone();
+                children.sort_by(|&a, &b| self.nodes[a].name.cmp(&self.nodes[b].name));
+                for child in children {
+                    self.fmt_node(f, child, depth + 1)?;
+                }
+                Ok(())
+            }
+            Kind::File { .. } => writeln!(f, "{indent}- {} (file, size={})", node.name, node.size),
+        }
+    }
+}
+
+/// Draws the tree as the puzzle does, each directory's contents indented under it by name
+impl Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_node(f, Self::ROOT, 0)
+    }
+}
+
+// a name of anything but whitespace: a-1.tar.gz
+pub fn parse_name(input: &str) -> IResult<'_, &str> {
+    take_till1(char::is_whitespace)(input).map_err(|e: nom::Err<Expected<'_>>| {
+        e.map(|e| Expected {
+            expected: "a name".to_string(),
+            ..e
+        })
+    })
+}
+
+// parse: $ cd|ls {dir}
+pub fn parse_command(input: &str) -> IResult<'_, Command<'_>> {
+    let (input, _) = character::complete::char('$')(input)?;
+    let (input, _) = space1(input)?;
+
+    let directory = map(parse_name, |s| match s {
+        ".." => Directory::Parent,
+        "/" => Directory::Root,
+        _ if s.starts_with('/') => Directory::Absolute(s),
+        _ => Directory::Path(s),
+    });
+
+    alt((
+        value(Command::List, tag("ls")),
+        map(
+            preceded(tuple((tag("cd"), space1)), directory),
+            Command::ChangeDirectory,
+        ),
+    ))(input)
+}
+
+// parse file size and name: {u64} {name}
+pub fn parse_file_size(input: &str) -> IResult<'_, FileRef<'_>> {
+    let (input, size) = character::complete::u64(input)?;
+    let (input, _) = space1(input)?;
+    let (input, name) = parse_name(input)?;
+
+    Ok((input, FileRef::new(name, size)))
+}
+
+// parse dir listing: dir {name}
+pub fn parse_dir(input: &str) -> IResult<'_, &str> {
+    let (input, _) = tag("dir")(input)?;
+    let (input, _) = space1(input)?;
+    parse_name(input)
+}
+
+/// A line of the terminal output
+#[derive(PartialEq, Eq, Debug)]
+pub enum Line<'a> {
+    Command(Command<'a>),
+    Dir(&'a str),
+    File(FileRef<'a>),
+}
+
+/// Writes the line as it appears in the terminal
+impl Display for Line<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Line::Command(command) => write!(f, "{command}"),
+            Line::Dir(name) => write!(f, "dir {name}"),
+            Line::File(file) => write!(f, "{} {}", file.size, file.full_name()),
+        }
+    }
+}
+
+/// Parse a line of the terminal output, a command or the output of ls
+pub fn parse_line(line: &str) -> Result<Line<'_>, ParseError> {
+    if line.starts_with('$') {
+        parse_all(line, map(parse_command, Line::Command))
+    } else {
+        parse_all(
+            line,
+            alt((map(parse_dir, Line::Dir), map(parse_file_size, Line::File))),
+        )
+    }
+}
+
+/// Create a new, empty directory under the system's temporary directory
+pub fn create_temp_dir() -> io::Result<PathBuf> {
+    static CREATED: AtomicUsize = AtomicUsize::new(0);
+
+    loop {
+        let n = CREATED.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("aoc-day-7-{}-{n}", std::process::id()));
+
+        match fs::create_dir(&path) {
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            result => return result.map(|_| path),
+        }
+    }
+}
+
+/// Replays the terminal output, building the tree of the files and directories it lists
+pub fn build_tree(reader: impl BufRead) -> Result<Tree, Box<dyn Error>> {
+    let mut tree = Tree::new();
+    let mut current_dir = Tree::ROOT;
+
+    for (number, line) in reader.lines().enumerate() {
+        let line = &line?;
+        if line.is_empty() {
+            continue;
+        }
+
+        let on_line = |e: String| format!("line {}: {e}", number + 1);
+        match parse_line(line).map_err(|e| e.on_line(number + 1))? {
+            Line::Command(command) => match command {
+                Command::ChangeDirectory(path) => match path {
+                    Directory::Root => current_dir = Tree::ROOT,
+                    Directory::Parent => {
+                        current_dir = tree.node(current_dir).parent.unwrap_or(Tree::ROOT);
+                    }
+                    Directory::Absolute(path) => {
+                        current_dir = tree.walk(Tree::ROOT, path).map_err(on_line)?;
+                    }
+                    Directory::Path(path) => {
+                        current_dir = tree.walk(current_dir, path).map_err(on_line)?;
+                    }
+                },
+                Command::List => {
+                    // just a noop, we're going to assume any dirs or follows all follow a directory listing command...
+                }
+            },
+            Line::Dir(dir) => {
+                tree.add_dir(current_dir, dir).map_err(on_line)?;
+            }
+            Line::File(file) => {
+                tree.add_file(current_dir, &file).map_err(on_line)?;
+            }
+        }
+    }
+
+    Ok(tree)
+}
+
+/// Sum of the sizes of all the directories at most `max_size` large
+pub fn sum_small_directories(tree: &Tree, max_size: usize) -> usize {
+    tree.directories()
+        .map(|id| tree.size(id))
+        .filter(|size| *size <= max_size)
+        .sum()
+}
+
+/// Size of the smallest directory that would free enough space for the update
+pub fn smallest_directory_to_free(tree: &Tree) -> Option<usize> {
+    tree.smallest_to_free(TOTAL_DISK_SIZE, SPARE_DISK_NEED)
+        .map(|id| tree.size(id))
+}
+
+/// The files and directories found from the terminal output
+pub struct FileSystem {
+    pub tree: Tree,
+}
+
+impl Solution for FileSystem {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            tree: build_tree(input.as_bytes())?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(sum_small_directories(&self.tree, 100000).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        let size = smallest_directory_to_free(&self.tree)
+            .ok_or("no directories found to free enough space")?;
+        Ok(size.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(
+            parse_command("$ cd ..").unwrap().1,
+            Command::ChangeDirectory(Directory::Parent),
+        );
+        assert_eq!(
+            parse_command("$ cd /").unwrap().1,
+            Command::ChangeDirectory(Directory::Root),
+        );
+        assert_eq!(
+            parse_command("$ cd abc").unwrap().1,
+            Command::ChangeDirectory(Directory::Path("abc")),
+        );
+    }
+
+    #[test]
+    fn test_parse_file() {
+        assert_eq!(
+            parse_file_size("12345 file.txt").unwrap().1,
+            FileRef {
+                name: "file",
+                extension: Some("txt"),
+                size: 12345
+            }
+        );
+
+        assert_eq!(
+            parse_file_size("12345 file").unwrap().1,
+            FileRef {
+                name: "file",
+                extension: None,
+                size: 12345
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_names() {
+        assert_eq!(parse_dir("dir ddddd").unwrap().1, "ddddd");
+        assert_eq!(parse_dir("dir a-1").unwrap().1, "a-1");
+        assert_eq!(
+            parse_command("$ cd my_dir").unwrap().1,
+            Command::ChangeDirectory(Directory::Path("my_dir")),
+        );
+        assert_eq!(
+            parse_command("$ cd /a/b").unwrap().1,
+            Command::ChangeDirectory(Directory::Absolute("/a/b")),
+        );
+
+        let file = |name| parse_file_size(name).unwrap().1;
+        assert_eq!(
+            file("123 file.tar.gz"),
+            FileRef {
+                name: "file",
+                extension: Some("tar.gz"),
+                size: 123
+            }
+        );
+        assert_eq!(file("1 .profile").extension, None);
+        assert_eq!(file("1 a.").extension, None);
+        for name in ["file.tar.gz", ".profile", "a.", "a..b", "b"] {
+            assert_eq!(FileRef::new(name, 1).full_name(), name);
+        }
+
+        assert_eq!(
+            parse_all("dir ", parse_dir).unwrap_err(),
+            ParseError::new(5, "a name")
+        );
+    }
+
+    #[test]
+    fn test_bad_lines() {
+        let error = build_tree("$ cd /\n$ rm a\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            *error.downcast::<ParseError>().unwrap(),
+            ParseError::new(3, "'ls' or 'cd'").on_line(2)
+        );
+
+        let error = build_tree("$ ls\n12 a.txt\nfile b\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected 'dir' or a number"
+        );
+    }
+
+    const EXAMPLE: &str = r#"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+"#;
+
+    #[test]
+    fn test_example() {
+        let tree = build_tree(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(sum_small_directories(&tree, 100000), 95437);
+        assert_eq!(smallest_directory_to_free(&tree), Some(24933642));
+    }
+
+    #[test]
+    fn test_tree() {
+        let tree = build_tree(EXAMPLE.as_bytes()).unwrap();
+
+        assert_eq!(
+            tree.to_string(),
+            "\
+- / (dir)
+  - a (dir)
+    - e (dir)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir)
+    - d.ext (file, size=5626152)
+    - d.log (file, size=8033020)
+    - j (file, size=4060174)
+    - k (file, size=7214296)
+"
+        );
+
+        let e = tree
+            .child(tree.child(Tree::ROOT, "a").unwrap(), "e")
+            .unwrap();
+        assert_eq!(tree.path(e), "/a/e");
+        assert_eq!(tree.size(e), 584);
+        assert_eq!(tree.size(Tree::ROOT), 48381165);
+    }
+
+    #[test]
+    fn test_queries() {
+        let tree = build_tree(EXAMPLE.as_bytes()).unwrap();
+
+        let largest: Vec<_> = tree
+            .largest_directories(2)
+            .into_iter()
+            .map(|id| tree.path(id))
+            .collect();
+        assert_eq!(largest, ["/", "/d"]);
+
+        let extensions = tree.files_by_extension();
+        assert_eq!(
+            extensions[&None],
+            (5, 4060174 + 7214296 + 29116 + 2557 + 584)
+        );
+        assert_eq!(extensions[&Some("txt")], (1, 14848514));
+
+        let d = tree.smallest_to_free(70000000, 30000000).unwrap();
+        assert_eq!(tree.path(d), "/d");
+        // a bigger disk leaves more free, so deleting e is enough
+        let e = tree.smallest_to_free(78381000, 30000000).unwrap();
+        assert_eq!(tree.path(e), "/a/e");
+
+        assert!(!tree.update_fits(70000000, 30000000));
+        assert!(tree.update_fits(48381165 + 30000000, 30000000));
+        // an update too big to add to what's used can't fit, rather than wrapping around
+        assert!(!tree.update_fits(usize::MAX, usize::MAX));
+    }
+
+    #[test]
+    fn test_listed_twice() {
+        let tree = build_tree("$ ls\n10 a\n$ ls\n10 a\n".as_bytes()).unwrap();
+        assert_eq!(tree.size(Tree::ROOT), 10);
+
+        let error = build_tree("$ ls\n10 a\ndir a\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3: /a is both a file and a directory"
+        );
+    }
+
+    #[test]
+    fn test_paths() {
+        let input = "\
+$ cd /a/b
+$ ls
+10 x.tar.gz
+$ cd ../c/d
+$ ls
+20 y
+$ cd /
+$ cd a/b/../../a/c
+$ ls
+30 z.tar.gz
+";
+        let tree = build_tree(input.as_bytes()).unwrap();
+        let c = tree
+            .child(tree.child(Tree::ROOT, "a").unwrap(), "c")
+            .unwrap();
+
+        assert_eq!(tree.path(c), "/a/c");
+        assert_eq!(tree.size(c), 50);
+        assert_eq!(tree.size(Tree::ROOT), 60);
+        assert_eq!(tree.files_by_extension()[&Some("tar.gz")], (2, 40));
+    }
+
+    #[test]
+    fn test_lines_round_trip() {
+        for line in [
+            "$ ls",
+            "$ cd /",
+            "$ cd ..",
+            "$ cd /a/b",
+            "$ cd a",
+            "dir a-1",
+            "123 a.tar.gz",
+        ] {
+            assert_eq!(parse_line(line).unwrap().to_string(), line);
+        }
+
+        let tree = build_tree(EXAMPLE.as_bytes()).unwrap();
+        let mut transcript = Vec::new();
+        tree.write_transcript(&mut transcript).unwrap();
+        assert_eq!(build_tree(&transcript[..]).unwrap(), tree);
+
+        let error = build_tree("$ ls\ndir ..\n".as_bytes()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2: .. isn't a valid name");
+    }
+
+    #[test]
+    fn test_real_directory_round_trip() {
+        let tree = build_tree(EXAMPLE.as_bytes()).unwrap();
+        let root = create_temp_dir().unwrap();
+        tree.materialize(&root).unwrap();
+
+        let size = fs::metadata(root.join("d/d.log")).unwrap().len();
+        assert_eq!(size, 8033020);
+
+        // the entries are read back by name, so compare the drawings, which sort them too
+        let read = Tree::from_dir(&root).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(read.to_string(), tree.to_string());
+        assert_eq!(read.size(Tree::ROOT), tree.size(Tree::ROOT));
+    }
+}
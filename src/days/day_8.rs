@@ -0,0 +1,378 @@
+//! --- Day 8: Treetop Tree House ---
+//! The expedition comes across a peculiar patch of tall trees all planted carefully in a grid. The Elves explain that a previous expedition planted these trees as a reforestation effort. Now, they're curious if this would be a good location for a tree house.
+//!
+//! First, determine whether there is enough tree cover here to keep a tree house hidden. To do this, you need to count the number of trees that are visible from outside the grid when looking directly along a row or column.
+//!
+//! The Elves have already launched a quadcopter to generate a map with the height of each tree (your puzzle input). For example:
+//!
+//! 30373
+//! 25512
+//! 65332
+//! 33549
+//! 35390
+//! Each tree is represented as a single digit whose value is its height, where 0 is the shortest and 9 is the tallest.
+//!
+//! A tree is visible if all of the other trees between it and an edge of the grid are shorter than it. Only consider trees in the same row or column; that is, only look up, down, left, or right from any given tree.
+//!
+//! All of the trees around the edge of the grid are visible - since they are already on the edge, there are no trees to block the view. In this example, that only leaves the interior nine trees to consider:
+//!
+//! The top-left 5 is visible from the left and top. (It isn't visible from the right or bottom since other trees of height 5 are in the way.)
+//! The top-middle 5 is visible from the top and right.
+//! The top-right 1 is not visible from any direction; for it to be visible, there would need to only be trees of height 0 between it and an edge.
+//! The left-middle 5 is visible, but only from the right.
+//! The center 3 is not visible from any direction; for it to be visible, there would need to be only trees of at most height 2 between it and an edge.
+//! The right-middle 3 is visible from the right.
+//! In the bottom row, the middle 5 is visible, but the 3 and 4 are not.
+//! With 16 trees visible on the edge and another 5 visible in the interior, a total of 21 trees are visible in this arrangement.
+//!
+//! Consider your map; how many trees are visible from outside the grid?
+//!
+//! --- Part Two ---
+//! Content with the amount of tree cover available, the Elves just need to know the best spot to build their tree house: they would like to be able to see a lot of trees.
+//!
+//! To measure the viewing distance from a given tree, look up, down, left, and right from that tree; stop if you reach an edge or at the first tree that is the same height or taller than the tree under consideration. (If a tree is right on the edge, at least one of its viewing distances will be zero.)
+//!
+//! The Elves don't care about distant trees taller than those found by the rules above; the proposed tree house has large eaves to keep it dry, so they wouldn't be able to see higher than the tree house anyway.
+//!
+//! In the example above, consider the middle 5 in the second row:
+//!
+//! 30373
+//! 25512
+//! 65332
+//! 33549
+//! 35390
+//! Looking up, its view is not blocked; it can see 1 tree (of height 3).
+//! Looking left, its view is blocked immediately; it can see only 1 tree (of height 5, right next to it).
+//! Looking right, its view is not blocked; it can see 2 trees.
+//! Looking down, its view is blocked eventually; it can see 2 trees (one of height 3, then the tree of height 5 that blocks its view).
+//! A tree's scenic score is found by multiplying together its viewing distance in each of the four directions. For this tree, this is 4 (found by multiplying 1 * 1 * 2 * 2).
+//!
+//! However, you can do even better: consider the tree of height 5 in the middle of the fourth row:
+//!
+//! 30373
+//! 25512
+//! 65332
+//! 33549
+//! 35390
+//! Looking up, its view is blocked at 2 trees (by another tree with a height of 5).
+//! Looking left, its view is not blocked; it can see 2 trees.
+//! Looking down, its view is also not blocked; it can see 1 tree.
+//! Looking right, its view is blocked at 2 trees (by a massive tree of height 9).
+//! This tree's scenic score is 8 (2 * 2 * 1 * 2); this is the ideal spot for the tree house.
+//!
+//! Consider each tree on your map. What is the highest scenic score possible for any tree?
+//!
+
+use std::error::Error;
+use std::io::BufRead;
+
+use crate::geometry::{Point, CARDINALS};
+use crate::grid::Grid;
+use crate::image::{self, Image};
+use crate::solution::{Answer, Solution};
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct Tree {
+    pub height: usize,
+    pub row: usize,
+    pub col: usize,
+}
+
+/// Parse the heights of the trees, every row as wide as the first
+pub fn build_grid(reader: impl BufRead) -> Result<Grid<usize>, Box<dyn Error>> {
+    Grid::parse(reader, "a tree height from 0 to 9", |ch| {
+        char::from(ch).to_digit(10).map(|d| d as usize)
+    })
+}
+
+/// Every line of sight into the forest, each row and column from both ends, as the points along
+/// it in order from the edge
+fn lines_of_sight(width: usize, height: usize) -> impl Iterator<Item = Vec<Point<usize>>> {
+    let rows = (0..height).map(move |y| (0..width).map(|x| Point::new(x, y)).collect::<Vec<_>>());
+    let columns =
+        (0..width).map(move |x| (0..height).map(|y| Point::new(x, y)).collect::<Vec<_>>());
+
+    rows.chain(columns).flat_map(|line| {
+        let reversed = line.iter().rev().copied().collect();
+        [line, reversed]
+    })
+}
+
+/// Whether each tree can be seen from outside the forest, found with a sweep along every line of
+/// sight that remembers the tallest tree so far
+pub fn get_visible_trees(grid: &Grid<usize>) -> Grid<bool> {
+    let mut visible = Grid::new(grid.width(), grid.height(), false);
+
+    for line in lines_of_sight(grid.width(), grid.height()) {
+        let mut tallest = None;
+        for point in line {
+            let height = grid[point];
+            if tallest.is_none_or(|tallest| height > tallest) {
+                visible[point] = true;
+                tallest = Some(height);
+            }
+        }
+    }
+
+    visible
+}
+
+/// The scenic score of every tree, the product of how far it can see in each direction
+///
+/// Each line of sight is swept with a stack of the trees that could still block the view back
+/// toward its edge, tallest at the bottom. Trees shorter than the next are popped, as they can't
+/// block anything it doesn't, so every tree is pushed and popped once.
+pub fn scenic_scores(grid: &Grid<usize>) -> Grid<usize> {
+    let mut scores = Grid::new(grid.width(), grid.height(), 1);
+
+    for line in lines_of_sight(grid.width(), grid.height()) {
+        let mut blockers: Vec<(usize, usize)> = Vec::new();
+
+        for (i, point) in line.into_iter().enumerate() {
+            let height = grid[point];
+            while blockers.last().is_some_and(|&(_, h)| h < height) {
+                blockers.pop();
+            }
+
+            // to the first tree at least as tall, or the edge
+            let view = blockers.last().map_or(i, |&(j, _)| i - j);
+            scores[point] *= view;
+            blockers.push((i, height));
+        }
+    }
+
+    scores
+}
+
+/// The scenic score of one tree, looking along each direction in turn
+pub fn calculate_tree_visibility(grid: &Grid<usize>, tree: Tree) -> usize {
+    let point = Point::new(tree.col, tree.row);
+
+    // look along each direction until the edge, or a tree at least as tall
+    let view = |&direction| {
+        let mut view = 0;
+        for (_, height) in grid.ray(point, direction) {
+            view += 1;
+            if *height >= tree.height {
+                break;
+            }
+        }
+        view
+    };
+
+    CARDINALS.iter().map(view).product()
+}
+
+/// The highest scenic score of any tree, or `None` if there are no trees
+pub fn calculate_max_view_score(grid: &Grid<usize>) -> Option<usize> {
+    scenic_scores(grid).iter().map(|(_, score)| *score).max()
+}
+
+const RESET: &str = "\x1b[0m";
+
+/// Draw the forest, the visible trees' heights highlighted and the hidden ones dimmed with ANSI
+/// colors, or without color showing only the visible trees' heights and a `.` for the rest
+pub fn render_forest(grid: &Grid<usize>, ansi: bool) -> String {
+    let visible = get_visible_trees(grid);
+    let mut drawing = String::new();
+
+    for (y, row) in grid.rows().enumerate() {
+        for (x, height) in row.iter().enumerate() {
+            match (visible[Point::new(x, y)], ansi) {
+                (true, true) => drawing.push_str(&format!("\x1b[1;32m{height}")),
+                (false, true) => drawing.push_str(&format!("\x1b[2;37m{height}")),
+                (true, false) => drawing.push_str(&height.to_string()),
+                (false, false) => drawing.push('.'),
+            }
+        }
+        if ansi {
+            drawing.push_str(RESET);
+        }
+        drawing.push('\n');
+    }
+
+    drawing
+}
+
+/// How scenic each tree is from 0 to 1, on a log scale up to the best score, so the few very
+/// scenic trees don't wash out the rest
+fn scenic_heat(grid: &Grid<usize>) -> Grid<f64> {
+    let scores = scenic_scores(grid);
+    let best = scores.iter().map(|(_, score)| *score).max().unwrap_or(0);
+    let scale = (best as f64).ln_1p();
+
+    scores.map(|score| {
+        if scale > 0.0 {
+            (score as f64).ln_1p() / scale
+        } else {
+            0.0
+        }
+    })
+}
+
+/// Draw a heatmap of the scenic scores, as blocks in ANSI colors from black for a score of 0 to
+/// white for the best, or without color as characters from ` ` to `@`
+pub fn render_heatmap(grid: &Grid<usize>, ansi: bool) -> String {
+    const RAMP: &[u8] = b" .:-=+*#%@";
+    let heat = scenic_heat(grid);
+    let mut drawing = String::new();
+
+    for row in heat.rows() {
+        for &t in row {
+            if ansi {
+                let [r, g, b] = image::heat(t);
+                drawing.push_str(&format!("\x1b[38;2;{r};{g};{b}m\u{2588}"));
+            } else {
+                let shade = (t * (RAMP.len() - 1) as f64).round() as usize;
+                drawing.push(char::from(RAMP[shade]));
+            }
+        }
+        if ansi {
+            drawing.push_str(RESET);
+        }
+        drawing.push('\n');
+    }
+
+    drawing
+}
+
+/// An image of the forest, `scale` pixels to a tree, the visible trees in greens and the hidden
+/// ones in grays, both lighter for taller trees
+pub fn forest_image(grid: &Grid<usize>, scale: usize) -> Image {
+    let visible = get_visible_trees(grid);
+
+    Image::from_grid(grid, scale, |point, &height| {
+        let shade = (64 + height * 20) as u8;
+        if visible[point] {
+            [0, shade, 0]
+        } else {
+            [shade / 2, shade / 2, shade / 2]
+        }
+    })
+}
+
+/// An image of the scenic scores as heat, like [`render_heatmap`], `scale` pixels to a tree
+pub fn heatmap_image(grid: &Grid<usize>, scale: usize) -> Image {
+    let heat = scenic_heat(grid);
+    Image::from_grid(&heat, scale, |_, &t| image::heat(t))
+}
+
+pub struct Forest {
+    pub grid: Grid<usize>,
+}
+
+impl Solution for Forest {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            grid: build_grid(input.as_bytes())?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        let visible = get_visible_trees(&self.grid);
+        Ok(visible
+            .iter()
+            .filter(|(_, visible)| **visible)
+            .count()
+            .into())
+    }
+
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        let score = calculate_max_view_score(&self.grid).ok_or("no trees")?;
+        Ok(score.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: &str = r#"
+30373
+25512
+65332
+33549
+35390
+"#;
+
+    #[test]
+    fn test_example_part1() {
+        let data = DATA.as_bytes();
+
+        let grid = build_grid(data).unwrap();
+
+        // assert row and column lengths
+        assert_eq!(grid.height(), 5);
+        assert_eq!(grid.width(), 5);
+        let visible = get_visible_trees(&grid);
+        assert_eq!(visible.iter().filter(|(_, visible)| **visible).count(), 21);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let data = DATA.as_bytes();
+
+        let grid = build_grid(data).unwrap();
+
+        let max_view_score = calculate_max_view_score(&grid);
+        assert_eq!(max_view_score, Some(8))
+    }
+
+    #[test]
+    fn test_scores_match_looking() {
+        // wider than it is tall, with repeated heights to block the views
+        let grid = build_grid("3037311\n2551229\n6533265\n".as_bytes()).unwrap();
+        let scores = scenic_scores(&grid);
+
+        for (point, height) in grid.iter() {
+            let tree = Tree {
+                height: *height,
+                row: point.y,
+                col: point.x,
+            };
+            assert_eq!(
+                scores[point],
+                calculate_tree_visibility(&grid, tree),
+                "{point:?}"
+            );
+        }
+
+        let visible = get_visible_trees(&grid);
+        assert!(visible[Point::new(6, 1)] && visible[Point::new(2, 1)]);
+        assert!(!visible[Point::new(3, 1)]);
+    }
+
+    #[test]
+    fn test_bad_grids() {
+        let error = build_grid("303\n25\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a row 3 cells wide, like the first"
+        );
+
+        let grid = build_grid("".as_bytes()).unwrap();
+        assert_eq!(calculate_max_view_score(&grid), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = build_grid(DATA.as_bytes()).unwrap();
+
+        assert_eq!(
+            render_forest(&grid, false),
+            "30373\n255.2\n65.32\n3.5.9\n35390\n"
+        );
+        assert!(render_forest(&grid, true).starts_with("\x1b[1;32m3"));
+
+        // the tree scoring 8 is the hottest, beside trees scoring 1 and 3, and those on the edges
+        // score 0
+        let heatmap = render_heatmap(&grid, false);
+        assert_eq!(heatmap.lines().nth(3).unwrap(), " -@* ");
+        assert_eq!(heatmap.lines().next().unwrap(), "     ");
+
+        let image = heatmap_image(&grid, 3);
+        assert_eq!((image.width(), image.height()), (15, 15));
+        assert_eq!(image.pixel(7, 10), [255, 255, 255]);
+        assert_eq!(forest_image(&grid, 1).pixel(2, 2), [62, 62, 62]);
+    }
+}
@@ -673,155 +673,202 @@
 
 use std::collections::HashSet;
 use std::error::Error;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-use clap::Parser;
-
-/// Cli
-#[derive(Debug, Parser)]
-#[clap(name = "Advent of Code", version, about)]
-struct Cli {
-    /// Disable INFO messages, WARN and ERROR will remain
-    #[clap(short = 'f', long = "file")]
-    pub(crate) file: String,
-}
 
-#[derive(Debug)]
-#[repr(u8)]
-enum Direction {
-    Up = b'U',
-    Down = b'D',
-    Left = b'L',
-    Right = b'R',
+use nom::{
+    branch::alt,
+    character::complete::char,
+    combinator::{map, value},
+    sequence::separated_pair,
+};
+
+use crate::geometry::Point;
+use crate::grid::SparseGrid;
+use crate::parsing::{self, parse_lines, tag, IResult};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
 }
 
-impl From<char> for Direction {
-    fn from(ch: char) -> Self {
-        assert!(ch.is_ascii_alphabetic());
-        match u8::try_from(ch).expect("not ascii") {
-            b'U' => Direction::Up,
-            b'D' => Direction::Down,
-            b'L' => Direction::Left,
-            b'R' => Direction::Right,
-            _ => panic!("not a direction {ch}"),
+impl Direction {
+    /// The step in this direction, `(dx, dy)`, up being towards smaller `y` like the rows of a
+    /// [`SparseGrid`]
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+            Direction::UpLeft => (-1, -1),
+            Direction::UpRight => (1, -1),
+            Direction::DownLeft => (-1, 1),
+            Direction::DownRight => (1, 1),
         }
     }
 }
 
-#[derive(Debug, Default, Hash, PartialEq, Eq, Clone)]
-struct Position {
-    x: isize,
-    y: isize,
+// U|D|L|R|UL|UR|DL|DR
+pub fn parse_direction(input: &str) -> IResult<'_, Direction> {
+    // the diagonals first, as they start like the others
+    alt((
+        value(Direction::UpLeft, tag("UL")),
+        value(Direction::UpRight, tag("UR")),
+        value(Direction::DownLeft, tag("DL")),
+        value(Direction::DownRight, tag("DR")),
+        value(Direction::Up, tag("U")),
+        value(Direction::Down, tag("D")),
+        value(Direction::Left, tag("L")),
+        value(Direction::Right, tag("R")),
+    ))(input)
 }
 
-impl Position {
-    fn mov(&mut self, direction: &Direction) {
-        match direction {
-            Direction::Up => self.y += 1,
-            Direction::Down => self.y -= 1,
-            Direction::Left => self.x -= 1,
-            Direction::Right => self.x += 1,
-        }
+/// Move the knot a step to follow the other, if it's more than `max_link` steps away counting
+/// diagonal steps as one, towards it along each axis they're apart on
+///
+/// Returns whether the knot moved.
+pub fn catch_up(knot: &mut Point, other: Point, max_link: usize) -> bool {
+    let (dx, dy) = (other.x - knot.x, other.y - knot.y);
+    if dx.unsigned_abs().max(dy.unsigned_abs()) <= max_link {
+        return false;
     }
 
-    fn catch_up(&mut self, other: &Position) -> bool {
-        fn mov_and_diag(axis1: (&mut isize, isize), axis2: (&mut isize, isize)) -> bool {
-            let a = axis1.0;
-            let b = axis1.1;
-
-            let c = axis2.0;
-            let d = axis2.1;
-
-            if *a > b && *a - b > 1 {
-                *a -= 1;
-
-                if *c > d {
-                    *c -= 1;
-                } else if *c < d {
-                    *c += 1;
-                }
-                true
-            } else if *a < b && b - *a > 1 {
-                *a += 1;
-
-                if *c > d {
-                    *c -= 1;
-                } else if *c < d {
-                    *c += 1;
-                }
-                true
-            } else {
-                false
-            }
+    *knot = knot.offset(dx.signum(), dy.signum());
+    true
+}
+
+/// A motion of the head of the rope, `count` steps in one direction
+#[derive(Debug)]
+pub struct Motion {
+    pub direction: Direction,
+    pub count: usize,
+}
+
+// R 4
+pub fn parse_motion(input: &str) -> IResult<'_, Motion> {
+    map(
+        separated_pair(parse_direction, char(' '), parsing::usize),
+        |(direction, count)| Motion { direction, count },
+    )(input)
+}
+
+/// A rope of knots, the head first, each pulled along by the knot before it once they're more
+/// than `max_link` steps apart
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rope {
+    pub knots: Vec<Point>,
+    pub max_link: usize,
+}
+
+impl Rope {
+    /// A rope of `knots` knots, counting the head, all starting at the origin
+    pub fn new(knots: usize, max_link: usize) -> Result<Self, String> {
+        if knots == 0 {
+            return Err("a rope needs at least one knot, its head".to_string());
         }
 
-        mov_and_diag((&mut self.x, other.x), (&mut self.y, other.y))
-            || mov_and_diag((&mut self.y, other.y), (&mut self.x, other.x))
+        Ok(Self {
+            knots: vec![Point::default(); knots],
+            max_link,
+        })
     }
-}
 
-impl std::fmt::Display for Position {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({},{})", self.x, self.y)
+    pub fn head(&self) -> &Point {
+        &self.knots[0]
     }
-}
 
-fn calculate_moves(reader: impl BufRead, tail_len: usize) -> Result<usize, Box<dyn Error>> {
-    let mut unique_tail_positions = HashSet::<Position>::new();
+    pub fn tail(&self) -> &Point {
+        self.knots.last().expect("a rope has a head")
+    }
 
-    let mut head = Position::default();
-    let mut ropes = vec![Position::default(); tail_len];
+    /// Move the head a step, and each knot after it as far as it's pulled
+    pub fn step(&mut self, direction: Direction) {
+        let (dx, dy) = direction.offset();
+        self.knots[0] = self.knots[0].offset(dx, dy);
 
-    for line in reader.lines() {
-        let line = line?;
-        if line.is_empty() {
-            continue;
+        for i in 1..self.knots.len() {
+            let leader = self.knots[i - 1];
+            if !catch_up(&mut self.knots[i], leader, self.max_link) {
+                // the knots after this one have nothing new to follow
+                break;
+            }
         }
+    }
 
-        let mut split = line.split(' ');
-        let direction = split
-            .next()
-            .map(|s| s.chars().next().expect("no move"))
-            .map(Direction::from)
-            .expect("line missing move");
-        let count = split
-            .next()
-            .map(|s| usize::from_str_radix(s, 10).expect("bad count"))
-            .expect("line missing count");
-
-        for _ in 0..count {
-            head.mov(&direction);
-            let mut prev = head.clone();
-
-            for next in ropes.iter_mut() {
-                next.catch_up(&prev);
-                prev = next.clone();
+    /// The rope after each step of the motions, one step at a time
+    pub fn steps<'a>(self, motions: &'a [Motion]) -> impl Iterator<Item = Rope> + 'a {
+        motions
+            .iter()
+            .flat_map(|motion| std::iter::repeat_n(motion.direction, motion.count))
+            .scan(self, |rope, direction| {
+                rope.step(direction);
+                Some(rope.clone())
+            })
+    }
+
+    /// How many positions each knot visits over the motions, counting where it starts
+    pub fn visited_counts(self, motions: &[Motion]) -> Vec<usize> {
+        let mut visited: Vec<HashSet<Point>> = self
+            .knots
+            .iter()
+            .map(|&knot| HashSet::from([knot]))
+            .collect();
+
+        for rope in self.steps(motions) {
+            for (knot, visited) in rope.knots.into_iter().zip(&mut visited) {
+                visited.insert(knot);
             }
-            unique_tail_positions.insert(ropes.last().unwrap().clone());
         }
-    }
 
-    Ok(unique_tail_positions.len())
+        visited.iter().map(HashSet::len).collect()
+    }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    println!("{}", env!("CARGO_PKG_NAME"));
-    let args = Cli::parse();
+/// Every position the end of a rope with `tail_len` knots behind the head visits, the rest of
+/// the plane is unvisited
+pub fn tail_visits(motions: &[Motion], tail_len: usize) -> SparseGrid<bool> {
+    let mut visited = SparseGrid::new(false);
+    visited.insert(Point::new(0, 0), true);
 
-    let filename = &args.file;
+    let rope = Rope::new(tail_len + 1, 1).expect("a rope with a head");
+    for rope in rope.steps(motions) {
+        visited.insert(*rope.tail(), true);
+    }
 
-    let file = BufReader::new(File::open(filename)?);
-    let score = calculate_moves(file, 1)?;
+    visited
+}
+
+/// Number of unique positions visited by the tail of a rope with `tail_len` knots behind the head
+pub fn calculate_moves(motions: &[Motion], tail_len: usize) -> usize {
+    tail_visits(motions, tail_len).len()
+}
 
-    println!("part1, unique places: {score}");
+/// The series of motions of the head of the rope
+pub struct Motions {
+    pub motions: Vec<Motion>,
+}
 
-    let file = BufReader::new(File::open(filename)?);
-    let score = calculate_moves(file, 9)?;
+impl Solution for Motions {
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>> {
+        let motions = parse_lines(input.as_bytes(), parse_motion)?;
 
-    println!("part2, unique places: {score}");
+        Ok(Self { motions })
+    }
 
-    Ok(())
+    fn part1(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(calculate_moves(&self.motions, 1).into())
+    }
+
+    fn part2(&self) -> Result<Answer, Box<dyn Error>> {
+        Ok(calculate_moves(&self.motions, 9).into())
+    }
 }
 
 #[cfg(test)]
@@ -853,20 +900,74 @@ U 20
     #[test]
     fn test_example_data_part_1() {
         assert_eq!(
-            calculate_moves(BufReader::new(INPUT.as_bytes()), 1).unwrap(),
+            calculate_moves(&Motions::parse(INPUT).unwrap().motions, 1),
             13
         );
     }
 
+    #[test]
+    fn test_render_visits() {
+        let visited = tail_visits(&Motions::parse(INPUT).unwrap().motions, 1);
+        let image = visited.render(|&visited| if visited { '#' } else { '.' });
+
+        assert_eq!(image, "..##.\n...##\n.####\n....#\n####.\n");
+    }
+
     #[test]
     fn test_example_data_part_2() {
         assert_eq!(
-            calculate_moves(BufReader::new(INPUT.as_bytes()), 9).unwrap(),
+            calculate_moves(&Motions::parse(INPUT).unwrap().motions, 9),
             1
         );
         assert_eq!(
-            calculate_moves(BufReader::new(INPUT2.as_bytes()), 9).unwrap(),
+            calculate_moves(&Motions::parse(INPUT2).unwrap().motions, 9),
             36
         );
     }
+
+    #[test]
+    fn test_diagonals() {
+        let motions = Motions::parse("UR 3\nDL 1\nR 2\n").unwrap().motions;
+        assert_eq!(motions[0].direction, Direction::UpRight);
+        assert_eq!(motions[1].direction, Direction::DownLeft);
+
+        // the tail follows the head diagonally, a step behind
+        let rope = Rope::new(2, 1).unwrap();
+        let tails: Vec<_> = rope
+            .steps(&motions)
+            .map(|rope| rope.tail().to_string())
+            .collect();
+        assert_eq!(
+            tails,
+            ["(0,0)", "(1,-1)", "(2,-2)", "(2,-2)", "(2,-2)", "(3,-2)"]
+        );
+    }
+
+    #[test]
+    fn test_rope() {
+        let motions = Motions::parse(INPUT2).unwrap().motions;
+
+        let counts = Rope::new(10, 1).unwrap().visited_counts(&motions);
+        assert_eq!(counts.len(), 10);
+        assert_eq!(counts[9], 36);
+        assert!(counts.windows(2).all(|pair| pair[0] >= pair[1]));
+
+        // a slacker rope drags its tail less
+        let slack = Rope::new(10, 2).unwrap().visited_counts(&motions);
+        assert!(slack[9] < counts[9]);
+
+        // the head is where the motions leave it, however long the links
+        let last = Rope::new(3, 5).unwrap().steps(&motions).last().unwrap();
+        assert_eq!(last.head(), &Point::new(-11, -15));
+        assert!(last.knots.windows(2).all(|pair| {
+            let (a, b) = (&pair[0], &pair[1]);
+            a.x.abs_diff(b.x).max(a.y.abs_diff(b.y)) <= 5
+        }));
+
+        assert!(Rope::new(0, 1).is_err());
+        assert_eq!(
+            Rope::new(1, 1).unwrap().head(),
+            Rope::new(1, 1).unwrap().tail()
+        );
+    }
 }
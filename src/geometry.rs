@@ -0,0 +1,102 @@
+//! Points on a two dimensional plane
+//!
+//! The axes carry no implied direction, each day decides whether `y` grows up or down.
+
+use std::fmt;
+
+/// The four cardinal offsets, `(dx, dy)`
+pub const CARDINALS: [(isize, isize); 4] = [(0, 1), (0, -1), (-1, 0), (1, 0)];
+
+/// The eight offsets to the points around a point, the cardinals then the diagonals, `(dx, dy)`
+pub const ADJACENT: [(isize, isize); 8] = [
+    (0, 1),
+    (0, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, -1),
+    (1, -1),
+    (-1, 1),
+    (1, 1),
+];
+
+/// A point on the plane, usually `usize` for grids or `isize` for unbounded planes
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Point<usize> {
+    /// Moves the point by the offset, None if either axis would drop below zero
+    pub fn checked_offset(&self, dx: isize, dy: isize) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    /// All the points one step up, down, left or right of this one that don't fall below zero
+    pub fn cardinal_neighbors(&self) -> impl Iterator<Item = Self> + '_ {
+        CARDINALS
+            .iter()
+            .filter_map(|(dx, dy)| self.checked_offset(*dx, *dy))
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The same point as an `isize` one, for the unbounded plane
+    pub fn signed(&self) -> Point<isize> {
+        Point::new(self.x as isize, self.y as isize)
+    }
+}
+
+impl Point<isize> {
+    /// Moves the point by the offset
+    pub fn offset(&self, dx: isize, dy: isize) -> Self {
+        Self {
+            x: self.x + dx,
+            y: self.y + dy,
+        }
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_offset() {
+        let origin = Point::<usize>::new(0, 0);
+
+        assert_eq!(origin.checked_offset(1, 2), Some(Point::new(1, 2)));
+        assert_eq!(origin.checked_offset(-1, 0), None);
+        assert_eq!(origin.cardinal_neighbors().count(), 2);
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        let a = Point::new(8_isize, 7);
+        let b = Point::new(2_isize, 10);
+
+        assert_eq!(a.manhattan_distance(&b), 9);
+        assert_eq!(b.offset(6, -3), a);
+    }
+}
@@ -0,0 +1,547 @@
+//! Grids of cells: dense ones read from character maps, and sparse ones over the whole plane
+//!
+//! A [`Grid`]'s cells are addressed by [`Point<usize>`], where `x` is the column counted from the
+//! left and `y` is the row counted from the top. So `(0,0)` is the top-left cell and `y` grows
+//! downwards, the same order the lines of a map are read in. Offsets like [`CARDINALS`] follow the
+//! same convention, so `(0, -1)` steps up a row. A [`SparseGrid`] uses [`Point<isize>`] the same
+//! way, drawing its smallest `y` at the top.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::{Point, ADJACENT, CARDINALS};
+use crate::parsing::ParseError;
+
+/// A dense grid of `width` by `height` cells, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `cell`
+    pub fn new(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    /// A grid from its rows, top first, which must all be the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(format!(
+                "row {y} is {} cells wide, but row 0 is {width}",
+                rows[y].len()
+            ));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Read each non-empty line of the reader as a row of cells, converting each byte with `cell`
+    ///
+    /// The first line is row 0. A byte that `cell` rejects is reported as a [`ParseError`] saying
+    /// the `expected` cell was not found, as is a row that isn't as wide as the first.
+    pub fn parse(
+        reader: impl BufRead,
+        expected: &str,
+        mut cell: impl FnMut(u8) -> Option<T>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+
+            for (col, ch) in line.bytes().enumerate() {
+                let parsed = cell(ch)
+                    .ok_or_else(|| ParseError::new(col + 1, expected).on_line(number + 1))?;
+                cells.push(parsed);
+            }
+
+            let width = *width.get_or_insert(line.len());
+            if line.len() != width {
+                let expected = format!("a row {width} cells wide, like the first");
+                let error = ParseError::new(line.len().min(width) + 1, expected);
+                return Err(error.on_line(number + 1).into());
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the point is inside the grid
+    pub fn contains(&self, point: Point<usize>) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    fn offset(&self, point: Point<usize>) -> Option<usize> {
+        self.contains(point)
+            .then_some(point.y * self.width + point.x)
+    }
+
+    /// The cell at the point, or None if it's outside the grid
+    pub fn get(&self, point: Point<usize>) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point<usize>) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point in the grid, row by row from the top
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its point, row by row from the top
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The first point whose cell matches
+    pub fn position(&self, mut matches: impl FnMut(&T) -> bool) -> Option<Point<usize>> {
+        self.iter()
+            .find(|(_, cell)| matches(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// The cells of row `y`, from the left
+    ///
+    /// # Panics
+    ///
+    /// If the row is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside the grid");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Each row, from the top
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero, but an empty grid has no cells to chunk anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x`, from the top
+    ///
+    /// # Panics
+    ///
+    /// If the column is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Each column, from the left
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The cells from `from`, not including it, stepping by `(dx, dy)` until the edge
+    pub fn ray(
+        &self,
+        from: Point<usize>,
+        (dx, dy): (isize, isize),
+    ) -> impl Iterator<Item = (Point<usize>, &T)> {
+        let mut point = from;
+        std::iter::from_fn(move || {
+            point = point.checked_offset(dx, dy)?;
+            Some((point, self.get(point)?))
+        })
+    }
+
+    /// The cells one step up, down, left or right of the point, that are inside the grid
+    pub fn cardinal_neighbors(
+        &self,
+        point: Point<usize>,
+    ) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.neighbors(point, &CARDINALS)
+    }
+
+    /// The cells around the point, diagonals included, that are inside the grid
+    pub fn adjacent_neighbors(
+        &self,
+        point: Point<usize>,
+    ) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.neighbors(point, &ADJACENT)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        point: Point<usize>,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (Point<usize>, &'a T)> {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let next = point.checked_offset(dx, dy)?;
+            Some((next, self.get(next)?))
+        })
+    }
+
+    /// A grid of the same size with each cell converted by `f`
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.into_iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// The grid flipped over its top-left to bottom-right diagonal, so rows become columns
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |point| {
+            Point::new(point.y, point.x)
+        })
+    }
+
+    /// The grid turned a quarter clockwise, so the left column becomes the top row
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+        self.remap(height, self.width, |point| {
+            Point::new(point.y, height - 1 - point.x)
+        })
+    }
+
+    /// The grid turned a quarter anticlockwise, so the top row becomes the left column
+    pub fn rotate_anticlockwise(&self) -> Self {
+        let width = self.width;
+        self.remap(self.height, width, |point| {
+            Point::new(width - 1 - point.y, point.x)
+        })
+    }
+
+    /// A `width` by `height` grid, taking each cell from the point `source` gives in this one
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(Point<usize>) -> Point<usize>,
+    ) -> Self {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[source(Point::new(x, y))].clone());
+            }
+        }
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point} is outside the {width}x{height} grid"))
+    }
+}
+
+/// Draws the grid as its rows of cells, a line each
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The smallest rectangle holding a set of points, both corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub min: Point<isize>,
+    pub max: Point<isize>,
+}
+
+impl Bounds {
+    pub fn width(&self) -> usize {
+        self.min.x.abs_diff(self.max.x) + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.min.y.abs_diff(self.max.y) + 1
+    }
+
+    pub fn contains(&self, point: Point<isize>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Grow the bounds to hold the point
+    fn include(&mut self, point: Point<isize>) {
+        self.min = Point::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+}
+
+/// A grid over the whole plane that stores only the cells that have been set
+///
+/// Every other cell is the default, which can depend on the point, like the floor of a cave. The
+/// bounds of the set cells are kept as they're inserted.
+pub struct SparseGrid<T> {
+    cells: HashMap<Point<isize>, T>,
+    default: Box<dyn Fn(Point<isize>) -> T>,
+    bounds: Option<Bounds>,
+}
+
+impl<T: Clone + 'static> SparseGrid<T> {
+    /// A grid where every cell that hasn't been set is `default`
+    pub fn new(default: T) -> Self {
+        Self::with_default(move |_| default.clone())
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// A grid where a cell that hasn't been set is whatever `default` gives for its point
+    pub fn with_default(default: impl Fn(Point<isize>) -> T + 'static) -> Self {
+        Self {
+            cells: HashMap::new(),
+            default: Box::new(default),
+            bounds: None,
+        }
+    }
+
+    /// Set the cell, returning the one that was set there before
+    pub fn insert(&mut self, point: Point<isize>, cell: T) -> Option<T> {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(point),
+            None => {
+                self.bounds = Some(Bounds {
+                    min: point,
+                    max: point,
+                })
+            }
+        }
+
+        self.cells.insert(point, cell)
+    }
+
+    /// The cell at the point, or the default if it hasn't been set
+    pub fn get(&self, point: Point<isize>) -> T
+    where
+        T: Clone,
+    {
+        match self.cells.get(&point) {
+            Some(cell) => cell.clone(),
+            None => (self.default)(point),
+        }
+    }
+
+    /// The cell at the point if it has been set
+    pub fn get_set(&self, point: Point<isize>) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    /// Whether the cell at the point has been set
+    pub fn is_set(&self, point: Point<isize>) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// How many cells have been set
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The bounds of the cells that have been set, None until one is
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Every cell that has been set, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point<isize>, &T)> {
+        self.cells.iter().map(|(point, cell)| (*point, cell))
+    }
+
+    /// Draw the bounds of the set cells, a line per row from the smallest `y`, with `draw` giving
+    /// the character for each cell, set or not
+    pub fn render(&self, mut draw: impl FnMut(&T) -> char) -> String {
+        let Some(bounds) = self.bounds else {
+            return String::new();
+        };
+
+        let mut image = String::with_capacity((bounds.width() + 1) * bounds.height());
+        for y in bounds.min.y..=bounds.max.y {
+            for x in bounds.min.x..=bounds.max.x {
+                let point = Point::new(x, y);
+                let ch = match self.cells.get(&point) {
+                    Some(cell) => draw(cell),
+                    None => draw(&(self.default)(point)),
+                };
+                image.push(ch);
+            }
+            image.push('\n');
+        }
+
+        image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digit(ch: u8) -> Option<u32> {
+        char::from(ch).to_digit(10)
+    }
+
+    fn grid(map: &str) -> Grid<u32> {
+        Grid::parse(map.as_bytes(), "a digit", digit).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid("\n123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 0)), Some(&3));
+        assert_eq!(grid[Point::new(0, 1)], 4);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let error = Grid::parse("12\n1a".as_bytes(), "a digit", digit).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: expected a digit");
+
+        let error = Grid::parse("123\n12\n".as_bytes(), "a digit", digit).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a row 3 cells wide, like the first"
+        );
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = grid("123\n456\n789");
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6, 9]);
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(grid.columns().count(), 3);
+
+        let ray: Vec<_> = grid.ray(Point::new(0, 2), (1, -1)).collect();
+        assert_eq!(ray, [(Point::new(1, 1), &5), (Point::new(2, 0), &3)]);
+        assert_eq!(grid.ray(Point::new(0, 0), (-1, 0)).count(), 0);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid("123\n456\n789");
+        let cells = |neighbors: Vec<(Point<usize>, &u32)>| {
+            let mut cells: Vec<u32> = neighbors.into_iter().map(|(_, cell)| *cell).collect();
+            cells.sort_unstable();
+            cells
+        };
+
+        assert_eq!(
+            cells(grid.cardinal_neighbors(Point::new(0, 0)).collect()),
+            [2, 4]
+        );
+        assert_eq!(
+            cells(grid.cardinal_neighbors(Point::new(1, 1)).collect()),
+            [2, 4, 6, 8]
+        );
+        assert_eq!(
+            cells(grid.adjacent_neighbors(Point::new(0, 0)).collect()),
+            [2, 4, 5]
+        );
+        assert_eq!(grid.adjacent_neighbors(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid("123\n456");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), "36\n25\n14\n");
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+        assert_eq!(grid.position(|&cell| cell == 5), Some(Point::new(1, 1)));
+        assert_eq!(grid.map(|cell| cell * 2).row(0), [2, 4, 6]);
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::with_default(|point: Point<isize>| point.y == 2);
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.render(|_| '#'), "");
+
+        assert_eq!(grid.insert(Point::new(-1, 0), true), None);
+        assert_eq!(grid.insert(Point::new(1, 2), false), None);
+        assert_eq!(grid.insert(Point::new(1, 2), false), Some(false));
+
+        assert_eq!(grid.len(), 2);
+        assert!(grid.get(Point::new(-1, 0)));
+        assert!(grid.get(Point::new(100, 2)));
+        assert!(!grid.get(Point::new(100, 3)));
+        assert!(grid.is_set(Point::new(1, 2)));
+        assert_eq!(grid.get_set(Point::new(0, 2)), None);
+        assert_eq!(grid.iter().count(), 2);
+
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(
+            (bounds.min, bounds.max),
+            (Point::new(-1, 0), Point::new(1, 2))
+        );
+        assert_eq!((bounds.width(), bounds.height()), (3, 3));
+        assert!(bounds.contains(Point::new(0, 1)));
+        assert!(!bounds.contains(Point::new(2, 1)));
+
+        let image = grid.render(|&cell| if cell { '#' } else { '.' });
+        assert_eq!(image, "#..\n...\n##.\n");
+    }
+}
@@ -0,0 +1,159 @@
+//! Images drawn from grids, for looking at inputs too large for the terminal
+//!
+//! They can be written as binary PPM, which anything can produce and most viewers open, or PNG.
+
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::geometry::Point;
+use crate::grid::Grid;
+
+/// A color as its red, green and blue
+pub type Rgb = [u8; 3];
+
+/// An image in 8 bit RGB, row by row from the top left
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Draw each cell of the grid as a square of `scale` by `scale` pixels of its color
+    pub fn from_grid<T>(
+        grid: &Grid<T>,
+        scale: usize,
+        mut color: impl FnMut(Point<usize>, &T) -> Rgb,
+    ) -> Self {
+        let scale = scale.max(1);
+        let width = grid.width() * scale;
+        let height = grid.height() * scale;
+
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..grid.height() {
+            let row: Vec<Rgb> = (0..grid.width())
+                .map(|x| Point::new(x, y))
+                .map(|point| color(point, &grid[point]))
+                .collect();
+
+            for _ in 0..scale {
+                for pixel in &row {
+                    pixels.extend(std::iter::repeat_n(*pixel, scale));
+                }
+            }
+        }
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The color of the pixel, counting from the top left
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Write the image as a binary PPM, a short text header then the pixels
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels.concat())
+    }
+
+    pub fn write_png(&self, out: impl Write) -> Result<(), Box<dyn Error>> {
+        let mut encoder =
+            png::Encoder::new(out, u32::try_from(self.width)?, u32::try_from(self.height)?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels.concat())?;
+        Ok(writer.finish()?)
+    }
+
+    /// Save the image as a PNG or PPM, chosen by the file's extension
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        let open = || File::create(path).map(BufWriter::new);
+
+        match extension.to_ascii_lowercase().as_str() {
+            "png" => self.write_png(open()?),
+            "ppm" => Ok(self.write_ppm(open()?)?),
+            _ => Err(format!("{}: can only save .png or .ppm images", path.display()).into()),
+        }
+    }
+}
+
+/// A color on a scale of heat, from black through red and yellow to white as `t` goes from 0
+/// to 1
+pub fn heat(t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0) * 3.0;
+    let channel = |offset: f64| ((t - offset).clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    [channel(0.0), channel(1.0), channel(2.0)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Image {
+        let grid = Grid::from_rows(vec![vec![true, false], vec![false, true]]).unwrap();
+        Image::from_grid(
+            &grid,
+            2,
+            |_, &on| if on { [255, 255, 255] } else { [0, 0, 0] },
+        )
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let image = checkerboard();
+
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.pixel(1, 1), [255, 255, 255]);
+        assert_eq!(image.pixel(2, 1), [0, 0, 0]);
+        assert_eq!(image.pixel(3, 3), [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_write() {
+        let image = checkerboard();
+
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+
+        let mut png = Vec::new();
+        image.write_png(&mut png).unwrap();
+
+        let mut reader = png::Decoder::new(&png[..]).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(pixels, image.pixels.concat());
+    }
+
+    #[test]
+    fn test_heat() {
+        assert_eq!(heat(0.0), [0, 0, 0]);
+        assert_eq!(heat(0.5), [255, 128, 0]);
+        assert_eq!(heat(1.0), [255, 255, 255]);
+        assert_eq!(heat(2.0), [255, 255, 255]);
+    }
+}
@@ -0,0 +1,58 @@
+//! Reading the puzzle input from a file, or from stdin when the path is `-`
+
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+/// Whether the path means stdin, `-`
+pub fn is_stdin(path: &Path) -> bool {
+    path == Path::new("-")
+}
+
+/// The path for messages, `stdin` for `-`
+pub fn name(path: &Path) -> Cow<'_, str> {
+    if is_stdin(path) {
+        Cow::Borrowed("stdin")
+    } else {
+        path.to_string_lossy()
+    }
+}
+
+/// Open the file, or stdin for `-`
+pub fn open(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if is_stdin(path) {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(path)?)))
+    }
+}
+
+/// Read all of the file, or stdin for `-`
+pub fn read_to_string(path: &Path) -> Result<String, String> {
+    let mut input = String::new();
+    open(path)
+        .and_then(|mut reader| reader.read_to_string(&mut input))
+        .map_err(|e| format!("failed to read {}: {e}", name(path)))?;
+
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name() {
+        assert!(is_stdin(Path::new("-")));
+        assert!(!is_stdin(Path::new("input/day-1.txt")));
+        assert_eq!(name(Path::new("-")), "stdin");
+        assert_eq!(name(Path::new("input/day-1.txt")), "input/day-1.txt");
+    }
+
+    #[test]
+    fn test_read_to_string() {
+        let error = read_to_string(Path::new("input/day-0.txt")).unwrap_err();
+        assert!(error.starts_with("failed to read input/day-0.txt: "));
+    }
+}
@@ -0,0 +1,341 @@
+//! Sets of integers kept as sorted, disjoint, inclusive ranges
+//!
+//! Day 4's section assignments and day 15's sensor coverage along a row are both ranges that need
+//! combining, counting and looking between.
+
+use std::fmt::Debug;
+use std::ops::{Add, RangeInclusive, Sub};
+
+/// The integers an [`IntervalSet`] can hold
+pub trait Integer: Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> {
+    const ONE: Self;
+
+    /// How many integers there are from `start` to `end`, inclusive, where `start <= end`
+    fn count(start: Self, end: Self) -> usize;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ONE: Self = 1;
+
+                fn count(start: Self, end: Self) -> usize {
+                    end.abs_diff(start) as usize + 1
+                }
+            }
+        )*
+    };
+}
+
+integer!(usize, isize, u32, i32, u64, i64);
+
+/// Whether a range ending at `end` finishes before one starting at `start`, with a gap between
+fn before<T: Integer>(end: T, start: T) -> bool {
+    // `end < start` first, so `end + 1` can't overflow
+    end < start && end + T::ONE < start
+}
+
+/// A set of integers, stored as the fewest inclusive ranges that cover them
+///
+/// Ranges that overlap or touch are merged as they're inserted, so `1..=3` and `4..=6` become
+/// `1..=6`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    /// Sorted, with a gap between each range and the next
+    ranges: Vec<(T, T)>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the range, merging it with any it overlaps or touches; empty ranges are ignored
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // the ranges from `first` up to `last` overlap or touch the new one
+        let first = self.ranges.partition_point(|&(_, e)| before(e, start));
+        let last = self.ranges.partition_point(|&(s, _)| !before(end, s));
+
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    /// The ranges, in order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// How many ranges the set is made of
+    pub fn range_count(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// How many integers are in the set
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(|&(s, e)| T::count(s, e)).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, e)| e < value);
+        self.ranges.get(i).is_some_and(|&(s, _)| s <= value)
+    }
+
+    /// Whether every integer in the range is in the set
+    pub fn contains_range(&self, range: RangeInclusive<T>) -> bool {
+        let (start, end) = range.into_inner();
+        let i = self.ranges.partition_point(|&(_, e)| e < start);
+        start > end
+            || self
+                .ranges
+                .get(i)
+                .is_some_and(|&(s, e)| s <= start && end <= e)
+    }
+
+    /// The smallest range covering the whole set
+    pub fn span(&self) -> Option<RangeInclusive<T>> {
+        let first = self.ranges.first()?;
+        let last = self.ranges.last()?;
+        Some(first.0..=last.1)
+    }
+
+    /// The integers in either set
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+        union
+    }
+
+    /// The integers in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(s1, e1)), Some(&(s2, e2))) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (s1.max(s2), e1.min(e2));
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            // move on from whichever finishes first, the other might overlap the next one
+            if e1 < e2 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The integers in this set but not the other
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+
+        for &(mut start, end) in &self.ranges {
+            // skip the ranges that finish before this one starts
+            while other.ranges.get(j).is_some_and(|&(_, e)| e < start) {
+                j += 1;
+            }
+
+            let mut k = j;
+            let mut remains = true;
+            while let Some(&(s, e)) = other.ranges.get(k).filter(|&&(s, _)| s <= end) {
+                if start < s {
+                    ranges.push((start, s - T::ONE));
+                }
+                if e >= end {
+                    remains = false;
+                    break;
+                }
+                start = e + T::ONE;
+                k += 1;
+            }
+
+            if remains {
+                ranges.push((start, end));
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The ranges missing from the set between its first and last integers
+    pub fn gaps(&self) -> Self {
+        let ranges = self
+            .ranges
+            .windows(2)
+            .map(|pair| (pair[0].1 + T::ONE, pair[1].0 - T::ONE))
+            .collect();
+
+        Self { ranges }
+    }
+
+    /// The integers covered by at least `k` of the ranges, which may overlap, or by one if `k` is 0
+    pub fn covered_at_least(ranges: impl IntoIterator<Item = RangeInclusive<T>>, k: usize) -> Self {
+        let k = k.max(1);
+
+        // starts sort before ends at the same point, as the ranges include both
+        let mut events: Vec<(T, bool)> = ranges
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .flat_map(|range| [(*range.start(), false), (*range.end(), true)])
+            .collect();
+        events.sort_unstable();
+
+        let mut set = Self::new();
+        let mut depth = 0;
+        let mut start = None;
+
+        for (point, is_end) in events {
+            if is_end {
+                if depth == k {
+                    let start = start.take().expect("a start at this depth");
+                    set.insert(start..=point);
+                }
+                depth -= 1;
+            } else {
+                depth += 1;
+                if depth == k {
+                    start = Some(point);
+                }
+            }
+        }
+
+        set
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// Merge the ranges that overlap or touch, returning the fewest ranges that cover them, in order
+pub fn merge_overlapping<T: Integer>(
+    ranges: impl IntoIterator<Item = RangeInclusive<T>>,
+) -> Vec<RangeInclusive<T>> {
+    ranges
+        .into_iter()
+        .collect::<IntervalSet<T>>()
+        .iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<isize>]) -> IntervalSet<isize> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges(set: &IntervalSet<isize>) -> Vec<RangeInclusive<isize>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut intervals = set(&[10..=12, 1..=3]);
+        assert_eq!(ranges(&intervals), [1..=3, 10..=12]);
+
+        // touching ranges merge, ones with a gap don't
+        intervals.insert(4..=5);
+        intervals.insert(7..=7);
+        assert_eq!(ranges(&intervals), [1..=5, 7..=7, 10..=12]);
+
+        // one range can swallow several
+        intervals.insert(6..=11);
+        assert_eq!(ranges(&intervals), [1..=12]);
+
+        #[allow(clippy::reversed_empty_ranges)]
+        intervals.insert(20..=19);
+        assert_eq!(intervals.len(), 12);
+        assert_eq!(intervals.span(), Some(1..=12));
+
+        let extremes: IntervalSet<usize> = [usize::MAX..=usize::MAX, 0..=0].into_iter().collect();
+        assert_eq!(extremes.len(), 2);
+        assert_eq!(
+            merge_overlapping([-5..=-1, 0..=0, -10..=-7]),
+            [-10..=-7, -5..=0]
+        );
+    }
+
+    #[test]
+    fn test_contains() {
+        let intervals = set(&[1..=3, 7..=9]);
+        assert!(intervals.contains(1) && intervals.contains(9));
+        assert!(!intervals.contains(0) && !intervals.contains(5) && !intervals.contains(10));
+        assert!(intervals.contains_range(7..=8));
+        assert!(!intervals.contains_range(2..=7));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[1..=5, 10..=15]);
+        let b = set(&[4..=11, 14..=20]);
+
+        assert_eq!(ranges(&a.union(&b)), [1..=20]);
+        assert_eq!(ranges(&a.intersection(&b)), [4..=5, 10..=11, 14..=15]);
+        assert_eq!(ranges(&a.difference(&b)), [1..=3, 12..=13]);
+        assert_eq!(ranges(&b.difference(&a)), [6..=9, 16..=20]);
+        assert_eq!(ranges(&a.difference(&set(&[0..=20]))), []);
+        assert_eq!(
+            ranges(&set(&[0..=20]).difference(&a)),
+            [0..=0, 6..=9, 16..=20]
+        );
+        assert_eq!(ranges(&a.gaps()), [6..=9]);
+    }
+
+    #[test]
+    fn test_covered_at_least() {
+        let assignments = [2..=4, 6..=8, 2..=3, 4..=5, 5..=7, 7..=9];
+
+        assert_eq!(
+            ranges(&IntervalSet::covered_at_least(assignments.clone(), 1)),
+            [2..=9]
+        );
+        assert_eq!(
+            ranges(&IntervalSet::covered_at_least(assignments.clone(), 2)),
+            [2..=8]
+        );
+        assert_eq!(
+            ranges(&IntervalSet::covered_at_least(assignments, 3)),
+            [7..=7]
+        );
+    }
+}
@@ -0,0 +1,17 @@
+//! Advent of Code 2022
+//!
+//! The solvers for each day live in [`days`] and are listed in the [`registry`], which the `aoc`
+//! binary uses to find the day to run, e.g. `aoc run --day 12 --part 2`.
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod days;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod intervals;
+pub mod parsing;
+pub mod registry;
+pub mod solution;
@@ -0,0 +1,233 @@
+//! Helpers shared by the puzzle input parsers
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+
+use nom::error::ErrorKind;
+use nom::{character, combinator::map};
+
+/// The result of the nom parsers over the puzzle input
+pub type IResult<'a, T> = nom::IResult<&'a str, T, Expected<'a>>;
+
+/// An error in the puzzle input, pointing at where it went wrong
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input, starting at 1
+    pub line: usize,
+    /// Column of the line, starting at 1
+    pub column: usize,
+    /// What should have been at that position
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error at the column of the first line of the input
+    ///
+    /// Parsers that only see a single line use this and leave it to the caller to place
+    /// the error with [`ParseError::on_line`].
+    pub fn new(column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            line: 1,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// An error at the start of `rest`, the part of `input` that could not be parsed
+    pub fn at(input: &str, rest: &str, expected: impl Into<String>) -> Self {
+        let consumed = &input[..input.len() - rest.len()];
+        let line_start = consumed.rfind('\n').map_or(0, |idx| idx + 1);
+
+        Self {
+            line: consumed.matches('\n').count() + 1,
+            column: consumed[line_start..].chars().count() + 1,
+            expected: expected.into(),
+        }
+    }
+
+    /// Converts the error from a nom parser that was given `input`
+    pub fn from_nom(input: &str, error: nom::Err<Expected<'_>>) -> Self {
+        match error {
+            nom::Err::Incomplete(_) => Self::at(input, "", "more input"),
+            nom::Err::Error(e) | nom::Err::Failure(e) => Self::at(input, e.input, e.expected),
+        }
+    }
+
+    /// Moves the error to a larger input, where the input that was parsed starts on `line`
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line += line - 1;
+        self
+    }
+
+    /// Moves the error right, where the input that was parsed starts after `columns` characters
+    pub fn after(mut self, columns: usize) -> Self {
+        self.column += columns;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// The error of the nom parsers, what was expected at the input where parsing stopped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected<'a> {
+    pub input: &'a str,
+    pub expected: String,
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Expected<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self {
+            input,
+            expected: describe_kind(kind),
+        }
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        // the innermost parser knows best what it was looking for
+        other
+    }
+
+    fn from_char(input: &'a str, ch: char) -> Self {
+        Self {
+            input,
+            expected: format!("'{ch}'"),
+        }
+    }
+
+    fn or(self, other: Self) -> Self {
+        // of the alternatives, report the one that got furthest, or all of them if they tie
+        match self.input.len().cmp(&other.input.len()) {
+            Ordering::Less => self,
+            Ordering::Greater => other,
+            Ordering::Equal => Self {
+                expected: format!("{} or {}", self.expected, other.expected),
+                ..other
+            },
+        }
+    }
+}
+
+/// What a failed nom parser was looking for
+fn describe_kind(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => "a number".to_string(),
+        ErrorKind::Alpha => "a letter".to_string(),
+        ErrorKind::AlphaNumeric => "a letter or number".to_string(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace".to_string(),
+        ErrorKind::CrLf => "a line ending".to_string(),
+        ErrorKind::Eof => "the end of the input".to_string(),
+        kind => kind.description().to_lowercase(),
+    }
+}
+
+/// Run the nom parser over all of `input`, anything left over is an error
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> Result<T, ParseError> {
+    let (rest, value) = parser(input).map_err(|e| ParseError::from_nom(input, e))?;
+
+    if !rest.trim_end().is_empty() {
+        return Err(ParseError::at(input, rest, "the end of the line"));
+    }
+
+    Ok(value)
+}
+
+/// Matches the text exactly, like nom's `tag`, but remembers the text when it isn't found
+pub fn tag<'a>(text: &'static str) -> impl Fn(&'a str) -> IResult<'a, &'a str> {
+    move |input| {
+        nom::bytes::complete::tag(text)(input).map_err(|e: nom::Err<Expected<'a>>| {
+            e.map(|e| Expected {
+                expected: format!("'{text}'"),
+                ..e
+            })
+        })
+    }
+}
+
+/// Parse an unsigned decimal number into a usize
+pub fn usize(input: &str) -> IResult<'_, usize> {
+    map(character::complete::u64, |val| val as usize)(input)
+}
+
+/// Parse a possibly signed decimal number into an isize
+pub fn isize(input: &str) -> IResult<'_, isize> {
+    map(character::complete::i64, |val| val as isize)(input)
+}
+
+/// Parse each non-empty line with the parser
+pub fn parse_lines<T>(
+    reader: impl BufRead,
+    mut parser: impl FnMut(&str) -> IResult<'_, T>,
+) -> Result<Vec<T>, Box<dyn Error>> {
+    let mut parsed = Vec::new();
+
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.is_empty() {
+            continue;
+        }
+
+        let value = parse_all(&line, &mut parser).map_err(|e| e.on_line(number + 1))?;
+        parsed.push(value);
+    }
+
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(usize("123 abc").unwrap(), (" abc", 123));
+        assert_eq!(isize("-12").unwrap(), ("", -12));
+        assert!(usize("-12").is_err());
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let numbers = parse_lines("\n1\n\n2\n3\n".as_bytes(), usize).unwrap();
+        assert_eq!(numbers, vec![1, 2, 3]);
+
+        let error = parse_lines("1\n\n23x\n".as_bytes(), usize).unwrap_err();
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!(*error, ParseError::new(3, "the end of the line").on_line(3));
+    }
+
+    #[test]
+    fn test_parse_error_at() {
+        let input = "abc\ndef\nghi";
+        let error = ParseError::at(input, &input[6..], "a number");
+
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.to_string(), "line 2, column 3: expected a number");
+        assert_eq!(
+            parse_all("x1", usize).unwrap_err(),
+            ParseError::new(1, "a number")
+        );
+        assert_eq!(
+            parse_all("1-2", nom::sequence::tuple((usize, tag(", "), usize))).unwrap_err(),
+            ParseError::new(2, "', '")
+        );
+        assert_eq!(
+            parse_all("x", nom::branch::alt((usize, map(tag("old"), |_| 0)))).unwrap_err(),
+            ParseError::new(1, "a number or 'old'")
+        );
+    }
+}
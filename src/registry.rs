@@ -0,0 +1,81 @@
+//! Every solved day, looked up by its number
+
+use std::path::PathBuf;
+
+use crate::days::*;
+use crate::solution::{parse_boxed, Parser};
+
+/// A solved day of the calendar
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    /// Parses the input into the day's [`Solution`](crate::solution::Solution)
+    pub parse: Parser,
+}
+
+impl Day {
+    /// Where the input is expected when none is given, `input/day-N.txt`
+    pub fn default_input(&self) -> PathBuf {
+        PathBuf::from(format!("input/day-{}.txt", self.day))
+    }
+}
+
+macro_rules! day {
+    ($day:literal, $solution:ty, $title:literal) => {
+        Day {
+            day: $day,
+            title: $title,
+            parse: parse_boxed::<$solution>,
+        }
+    };
+}
+
+/// All of the days, in calendar order
+pub const DAYS: &[Day] = &[
+    day!(1, day_1::Inventory, "Calorie Counting"),
+    day!(2, day_2::StrategyGuide, "Rock Paper Scissors"),
+    day!(3, day_3::Rucksacks, "Rucksack Reorganization"),
+    day!(4, day_4::Assignments, "Camp Cleanup"),
+    day!(5, day_5::Cargo, "Supply Stacks"),
+    day!(6, day_6::Datastream, "Tuning Trouble"),
+    day!(7, day_7::FileSystem, "No Space Left On Device"),
+    day!(8, day_8::Forest, "Treetop Tree House"),
+    day!(9, day_9::Motions, "Rope Bridge"),
+    day!(10, day_10::Program, "Cathode-Ray Tube"),
+    day!(11, day_11::Notes, "Monkey in the Middle"),
+    day!(12, day_12::Heightmap, "Hill Climbing Algorithm"),
+    day!(13, day_13::Packets, "Distress Signal"),
+    day!(14, day_14::Scan, "Regolith Reservoir"),
+    day!(15, day_15::Sensors, "Beacon Exclusion Zone"),
+];
+
+/// Find the day by its number
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_in_order() {
+        for (i, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.day as usize, i + 1);
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        let day = find(12).expect("day 12 is solved");
+        assert_eq!(day.title, "Hill Climbing Algorithm");
+        assert_eq!(day.default_input(), PathBuf::from("input/day-12.txt"));
+
+        let grid = (day.parse)("Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\n").unwrap();
+        assert_eq!(grid.solve(1).unwrap().to_string(), "31");
+        assert!(grid.solve(3).is_err());
+
+        assert!(find(0).is_none());
+        assert!(find(26).is_none());
+    }
+}
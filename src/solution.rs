@@ -0,0 +1,128 @@
+//! The interface every day's solver implements
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::BufRead;
+
+use serde::Serialize;
+
+/// The answer to one part of a puzzle
+///
+/// Serializes as a plain number, string or array of rows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// A rendered image, like the CRT on day 10, one row per line
+    Lines(Vec<String>),
+}
+
+impl Answer {
+    /// Split a rendered image into its rows
+    pub fn lines(image: &str) -> Self {
+        Answer::Lines(image.lines().map(str::to_string).collect())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Integer(n as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(n: isize) -> Self {
+        Answer::Integer(n as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+/// A day's puzzle, parsed once from the input and then solved for both parts
+pub trait Solution {
+    /// Parse the puzzle input into the model both parts are solved from
+    fn parse(input: &str) -> Result<Self, Box<dyn Error>>
+    where
+        Self: Sized;
+
+    /// Parse the puzzle input from a reader, like stdin or an open file
+    fn read(mut reader: impl BufRead) -> Result<Self, Box<dyn Error>>
+    where
+        Self: Sized,
+    {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Self::parse(&input)
+    }
+
+    fn part1(&self) -> Result<Answer, Box<dyn Error>>;
+
+    fn part2(&self) -> Result<Answer, Box<dyn Error>>;
+}
+
+impl dyn Solution {
+    /// Solve the part, 1 or 2
+    pub fn solve(&self, part: u8) -> Result<Answer, Box<dyn Error>> {
+        match part {
+            1 => self.part1(),
+            2 => self.part2(),
+            _ => Err(format!("there is no part {part}, only 1 and 2").into()),
+        }
+    }
+}
+
+/// Parses the input into a boxed [`Solution`], see [`parse_boxed`]
+pub type Parser = fn(&str) -> Result<Box<dyn Solution>, Box<dyn Error>>;
+
+/// Parse the input as `S`, erasing the type so that every day can be handled alike
+pub fn parse_boxed<S: Solution + 'static>(
+    input: &str,
+) -> Result<Box<dyn Solution>, Box<dyn Error>> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day_6::Datastream;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42_usize).to_string(), "42");
+        assert_eq!(Answer::from(-7_isize).to_string(), "-7");
+        assert_eq!(Answer::from("CMZ".to_string()).to_string(), "CMZ");
+        assert_eq!(Answer::lines("#.\n.#\n").to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn test_serialize() {
+        let json = |answer: Answer| serde_json::to_string(&answer).unwrap();
+
+        assert_eq!(json(Answer::from(12848882750_usize)), "12848882750");
+        assert_eq!(json(Answer::from("CMZ".to_string())), r#""CMZ""#);
+        assert_eq!(json(Answer::lines("#.\n.#\n")), r##"["#.",".#"]"##);
+    }
+
+    #[test]
+    fn test_read() {
+        let reader = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n".as_bytes();
+        let datastream = Datastream::read(reader).unwrap();
+        assert_eq!(datastream.part1().unwrap(), Answer::Integer(7));
+        assert_eq!(datastream.part2().unwrap(), Answer::Integer(19));
+    }
+}
@@ -0,0 +1,66 @@
+//! A test per day, checking its answers for `input/day-N.txt` against `answers.toml`
+
+use std::fs;
+use std::path::Path;
+
+use aoc_2022::answers::{self, Answers};
+use aoc_2022::registry;
+
+fn answers() -> Answers {
+    Answers::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")).unwrap()
+}
+
+fn verify(day: u8) {
+    let solved = registry::find(day).expect("the day is solved");
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(solved.default_input());
+    let input = fs::read_to_string(&path).unwrap();
+
+    let checks = answers::check_day(solved, &input, &answers());
+    assert!(!checks.is_empty(), "day {day} has no accepted answers");
+
+    for check in checks {
+        assert!(check.passed(), "{check}");
+    }
+}
+
+macro_rules! answer_tests {
+    ($($(#[$attr:meta])* $name:ident: $day:literal,)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                verify($day);
+            }
+        )*
+
+        /// Every day with a test above
+        const TESTED: &[u8] = &[$($day),*];
+    };
+}
+
+answer_tests! {
+    day_1: 1,
+    day_2: 2,
+    day_3: 3,
+    day_4: 4,
+    day_5: 5,
+    day_6: 6,
+    day_7: 7,
+    day_8: 8,
+    day_9: 9,
+    day_10: 10,
+    day_11: 11,
+    #[ignore = "slow without optimizations, run with --release --ignored"]
+    day_12: 12,
+    day_13: 13,
+    day_14: 14,
+    #[ignore = "slow without optimizations, run with --release --ignored"]
+    day_15: 15,
+}
+
+#[test]
+fn every_answered_day_is_tested() {
+    for day in answers().days() {
+        assert!(TESTED.contains(&day), "day {day} has answers but no test");
+    }
+}